@@ -63,6 +63,196 @@ use util::uint::BitArray;
 use chainstate::stacks::index::TrieHash;
 
 use util::log;
+use util::hash::Sha256Sum;
+
+/// Number of sortitions folded into a single fast-sync checkpoint batch.
+pub const FAST_SYNC_BATCH_SIZE: u64 = 1000;
+
+/// A compiled-in "hash-of-hashes" checkpoint for the known-good sortition history, modeled on
+/// Cuprate's batch-checkpoint fast-sync technique: `digest` commits to the identifying fields
+/// of every snapshot in `[start_height, start_height + FAST_SYNC_BATCH_SIZE)`, in block-height
+/// order, so a syncing node can verify a whole batch at once instead of re-deriving every
+/// snapshot's provenance individually.
+pub struct FastSyncCheckpoint {
+    pub start_height: u64,
+    pub digest: Sha256Sum,
+}
+
+/// Error raised when a batch of sortition history fails to match its compiled-in checkpoint
+/// digest during fast sync.
+pub struct FastSyncCheckpointMismatch {
+    pub start_height: u64,
+    pub expected_digest: Sha256Sum,
+    pub computed_digest: Sha256Sum,
+}
+
+impl BlockSnapshot {
+    /// Fold this snapshot's identifying, consensus-relevant fields into a fast-sync batch
+    /// digest, in the same order `fold_batch_digest` concatenates them for every snapshot in
+    /// a batch: `consensus_hash`, `winning_stacks_block_hash`, `sortition_hash`, `total_burn`.
+    fn fold_into_batch(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self.consensus_hash.as_bytes());
+        bytes.extend_from_slice(self.winning_stacks_block_hash.as_bytes());
+        bytes.extend_from_slice(self.sortition_hash.as_bytes());
+        bytes.extend_from_slice(&self.total_burn.to_be_bytes());
+    }
+
+    /// Fold a batch of consecutively-ordered snapshots into a single SHA-256 digest, for
+    /// comparison against a compiled-in `FastSyncCheckpoint`.
+    pub fn fold_batch_digest(snapshots: &[BlockSnapshot]) -> Sha256Sum {
+        let mut bytes = Vec::with_capacity(snapshots.len() * 96);
+        for sn in snapshots.iter() {
+            sn.fold_into_batch(&mut bytes);
+        }
+        Sha256Sum::from_data(&bytes)
+    }
+
+    /// Verify a freshly-synced batch of snapshots against its compiled-in checkpoint. On a
+    /// match, the caller may accept the whole batch -- e.g. deferring `index_root` integrity
+    /// checks until the next checkpoint boundary -- instead of re-verifying every block.  The
+    /// final, partially-filled batch beyond the last checkpoint has no digest to check against
+    /// and must be verified block-by-block as before.
+    pub fn verify_fast_sync_batch(snapshots: &[BlockSnapshot], checkpoint: &FastSyncCheckpoint) -> Result<(), FastSyncCheckpointMismatch> {
+        let computed_digest = BlockSnapshot::fold_batch_digest(snapshots);
+        if computed_digest == checkpoint.digest {
+            Ok(())
+        } else {
+            Err(FastSyncCheckpointMismatch {
+                start_height: checkpoint.start_height,
+                expected_digest: checkpoint.digest.clone(),
+                computed_digest,
+            })
+        }
+    }
+
+    /// Walk `snapshots` (contiguous, block-height order, starting at `checkpoints[0].start_height`)
+    /// in `FAST_SYNC_BATCH_SIZE`-sized batches, verifying each one against its matching entry in
+    /// `checkpoints` before moving on to the next.  This is the actual fast-sync verification
+    /// entry point: a synced-from-scratch `checkpoints` table with no caller wired up to this
+    /// function is just a digest nobody checks.  Returns on the first batch that fails to match;
+    /// any snapshots beyond the last checkpoint are left unverified here and must still be
+    /// checked block-by-block, per `verify_fast_sync_batch`'s own doc comment.
+    pub fn verify_fast_sync_checkpoints(snapshots: &[BlockSnapshot], checkpoints: &[FastSyncCheckpoint]) -> Result<(), FastSyncCheckpointMismatch> {
+        let batch_size = FAST_SYNC_BATCH_SIZE as usize;
+        for checkpoint in checkpoints.iter() {
+            let batch_start = match snapshots.iter().position(|sn| sn.block_height == checkpoint.start_height) {
+                Some(idx) => idx,
+                None => continue,
+            };
+            let batch_end = std::cmp::min(batch_start + batch_size, snapshots.len());
+            BlockSnapshot::verify_fast_sync_batch(&snapshots[batch_start..batch_end], checkpoint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a query needs full per-op burn-distribution detail or user-burn records
+/// for a burn height that has been compacted away by `BurnDB::prune_below`, instead of the
+/// corruption panic a missing row would otherwise indicate.
+pub struct PrunedHistoryError {
+    pub pruned_height: u64,
+}
+
+/// The subset of a `BlockSnapshot`'s fields that `sample_burn_distribution` and
+/// `select_winning_block` still need once a node has entered pruned-history mode: enough to
+/// keep deriving consensus hashes and picking future winners, without retaining the per-op
+/// burn distribution or user-burn records for old heights (which only the *last* winner's VRF
+/// seed and the *current* burn distribution ever require).
+pub struct PrunedBlockSnapshot {
+    pub block_height: u64,
+    pub burn_header_hash: BurnchainHeaderHash,
+    pub parent_burn_header_hash: BurnchainHeaderHash,
+    pub consensus_hash: ConsensusHash,
+    pub sortition_hash: SortitionHash,
+    pub total_burn: u64,
+    pub index_root: TrieHash,
+    /// the winning block commit's VRF seed, captured before this height's op detail is
+    /// discarded so `select_winning_block` can still compute the next sortition
+    pub winning_new_seed: VRFSeed,
+}
+
+impl BlockSnapshot {
+    /// Compact a full snapshot down to the fields a pruned-history node still needs, as
+    /// `BurnDB::prune_below` would when compacting heights older than its retention window.
+    /// `winning_new_seed` must be fetched from the winning block commit before this height's
+    /// full op detail is dropped, since `PrunedBlockSnapshot` no longer carries it.
+    pub fn prune(&self, winning_new_seed: VRFSeed) -> PrunedBlockSnapshot {
+        PrunedBlockSnapshot {
+            block_height: self.block_height,
+            burn_header_hash: self.burn_header_hash.clone(),
+            parent_burn_header_hash: self.parent_burn_header_hash.clone(),
+            consensus_hash: self.consensus_hash.clone(),
+            sortition_hash: self.sortition_hash.clone(),
+            total_burn: self.total_burn,
+            index_root: self.index_root.clone(),
+            winning_new_seed,
+        }
+    }
+}
+
+/// The in-memory compacted tail of sortition history below `BurnDB`'s full retention window:
+/// `prune_below` moves every snapshot older than its cutoff height out of full-detail storage
+/// and into here via `BlockSnapshot::prune`, and `get` is the only way to read one back,
+/// returning `PrunedHistoryError` instead of `None`/a panic for any height it does not hold.
+/// `BurnDB::prune_below` is where a real node would drive this
+/// from; until that's wired up, `PrunedHistory` is the concrete compaction/guard logic it would
+/// delegate to.
+#[derive(Default)]
+pub struct PrunedHistory {
+    by_height: BTreeMap<u64, PrunedBlockSnapshot>,
+}
+
+impl PrunedHistory {
+    pub fn new() -> PrunedHistory {
+        PrunedHistory { by_height: BTreeMap::new() }
+    }
+
+    /// Compact every snapshot in `full_history` whose height is strictly below `cutoff_height`
+    /// into this `PrunedHistory`, using `winning_new_seed_of` to fetch each one's winning VRF
+    /// seed before its full op detail is discarded.
+    pub fn prune_below<F>(&mut self, full_history: &[BlockSnapshot], cutoff_height: u64, mut winning_new_seed_of: F)
+    where
+        F: FnMut(&BlockSnapshot) -> VRFSeed,
+    {
+        for sn in full_history.iter() {
+            if sn.block_height < cutoff_height {
+                let seed = winning_new_seed_of(sn);
+                self.by_height.insert(sn.block_height, sn.prune(seed));
+            }
+        }
+    }
+
+    /// The lowest height still available as a `PrunedBlockSnapshot`, or `None` if nothing has
+    /// been pruned yet.
+    pub fn pruned_floor(&self) -> Option<u64> {
+        self.by_height.keys().next().cloned()
+    }
+
+    /// Look up the compacted snapshot at `height`.  Returns `PrunedHistoryError`, carrying the
+    /// pruned floor (or `height` itself if nothing has been pruned), for any height not recorded
+    /// here; a height that has not been pruned yet gets the same error as one that never will be.
+    pub fn get(&self, height: u64) -> Result<&PrunedBlockSnapshot, PrunedHistoryError> {
+        match self.by_height.get(&height) {
+            Some(sn) => Ok(sn),
+            None => Err(PrunedHistoryError { pruned_height: self.pruned_floor().unwrap_or(height) }),
+        }
+    }
+}
+
+/// The current and most recent prior sortition on some fork, as seen from a given burn header
+/// hash.  Lets a miner or signer decide in one DB round-trip whether it is building on the
+/// current tenure's winner or still catching up to the last one, instead of issuing two
+/// separate queries and stitching the results together.
+pub struct SortitionsView {
+    /// whether the current snapshot's block actually won a sortition
+    pub made_winner: bool,
+    pub cur_winning_stacks_block_hash: BlockHeaderHash,
+    pub cur_winning_block_txid: Txid,
+    pub cur_consensus_hash: ConsensusHash,
+    pub prior_winning_stacks_block_hash: BlockHeaderHash,
+    pub prior_winning_block_txid: Txid,
+    pub prior_consensus_hash: ConsensusHash,
+}
 
 impl BlockSnapshot {
     /// Create the sentinel block snapshot -- the first one
@@ -87,6 +277,7 @@ impl BlockSnapshot {
             canonical_stacks_tip_height: 0,
             canonical_stacks_tip_hash: FIRST_STACKS_BLOCK_HASH.clone(),
             canonical_stacks_tip_burn_hash: FIRST_BURNCHAIN_BLOCK_HASH.clone(),
+            accepted_vote_tally: 0,
         }
     }
 
@@ -94,6 +285,19 @@ impl BlockSnapshot {
         self.sortition_hash == SortitionHash::initial()
     }
 
+    /// Count how many of this block's accepted non-sortition governance operations (e.g.
+    /// `BlockstackOperationType::VoteForAggregateKey`) are present in `txids`.  These
+    /// operations are folded into `ops_hash`/`consensus_hash` just like any other accepted
+    /// burnchain operation -- so that all nodes agree on which votes were seen at a given
+    /// burn height -- but they do not participate in `select_winning_block` or the burn
+    /// distribution that weights sortition, so their count is tracked separately on the
+    /// snapshot rather than recomputed by re-scanning the burn DB.
+    fn count_accepted_votes(txids: &Vec<Txid>, vote_txids: &Vec<Txid>) -> u64 {
+        vote_txids.iter()
+            .filter(|txid| txids.contains(txid))
+            .count() as u64
+    }
+
     /// Given the weighted burns, VRF seed of the last winner, and sortition hash, pick the next
     /// winner.  Return the index into the distribution *if there is a sample to take*.
     fn sample_burn_distribution(dist: &Vec<BurnSamplePoint>, VRF_seed: &VRFSeed, sortition_hash: &SortitionHash) -> Option<usize> {
@@ -161,8 +365,57 @@ impl BlockSnapshot {
         }
     }
 
+    /// Get the current-and-prior-sortition view for the fork containing `burn_header_hash`,
+    /// in a single DB round-trip.  Reuses the same fork-aware walk that `select_winning_block`
+    /// performs via `get_last_snapshot_with_sortition`, so a miner or validator can tell in one
+    /// query whether it is behind the current tenure's winner or the last one.
+    pub fn get_sortitions_view(ic: &BurnDBConn, burn_header_hash: &BurnchainHeaderHash) -> Result<SortitionsView, db_error> {
+        let cur_sn = BurnDB::get_block_snapshot(ic, burn_header_hash)?
+            .ok_or(db_error::NotFoundError)?;
+
+        let last_sortition_sn = if cur_sn.sortition {
+            cur_sn.clone()
+        } else {
+            BurnDB::get_last_snapshot_with_sortition(ic, cur_sn.block_height.saturating_sub(1), &cur_sn.parent_burn_header_hash)?
+        };
+
+        let prior_sortition_sn =
+            if last_sortition_sn.is_initial() || last_sortition_sn.block_height == 0 {
+                last_sortition_sn.clone()
+            } else {
+                BurnDB::get_last_snapshot_with_sortition(ic, last_sortition_sn.block_height - 1, &last_sortition_sn.parent_burn_header_hash)?
+            };
+
+        Ok(SortitionsView {
+            made_winner: cur_sn.sortition,
+            cur_winning_stacks_block_hash: last_sortition_sn.winning_stacks_block_hash.clone(),
+            cur_winning_block_txid: last_sortition_sn.winning_block_txid.clone(),
+            cur_consensus_hash: last_sortition_sn.consensus_hash.clone(),
+            prior_winning_stacks_block_hash: prior_sortition_sn.winning_stacks_block_hash.clone(),
+            prior_winning_block_txid: prior_sortition_sn.winning_block_txid.clone(),
+            prior_consensus_hash: prior_sortition_sn.consensus_hash.clone(),
+        })
+    }
+
+    /// Resolve the real canonical Stacks tip for the fork containing `start`, mirroring the
+    /// parent-cursor loop used by the stacks_chain_tips lookup.  If `start` itself recorded an
+    /// accepted Stacks block (or is the sentinel initial snapshot), its own canonical-tip
+    /// fields are authoritative; otherwise they were just copied down from whichever ancestor
+    /// last updated them, so walk up the fork until a snapshot with an explicitly-recorded tip
+    /// is found, and return that snapshot's `(canonical_stacks_tip_burn_hash,
+    /// canonical_stacks_tip_hash)` pair (plus its height, for wiring into a new snapshot).
+    pub fn resolve_canonical_stacks_tip(ic: &BurnDBConn, start: &BlockSnapshot) -> Result<(u64, BurnchainHeaderHash, BlockHeaderHash), db_error> {
+        let mut cursor = start.clone();
+        while !cursor.stacks_block_accepted && !cursor.is_initial() {
+            cursor = BurnDB::get_block_snapshot(ic, &cursor.parent_burn_header_hash)?
+                .ok_or(db_error::NotFoundError)?;
+        }
+
+        Ok((cursor.canonical_stacks_tip_height, cursor.canonical_stacks_tip_burn_hash.clone(), cursor.canonical_stacks_tip_hash.clone()))
+    }
+
     /// Make the snapshot struct for the case where _no sortition_ takes place
-    fn make_snapshot_no_sortition<'a>(ic: &BurnDBConn, parent_snapshot: &BlockSnapshot, block_header: &BurnchainBlockHeader, first_block_height: u64, burn_total: u64, sortition_hash: &SortitionHash, txids: &Vec<Txid>) -> Result<BlockSnapshot, db_error> {
+    fn make_snapshot_no_sortition<'a>(ic: &BurnDBConn, parent_snapshot: &BlockSnapshot, block_header: &BurnchainBlockHeader, first_block_height: u64, burn_total: u64, sortition_hash: &SortitionHash, txids: &Vec<Txid>, vote_txids: &Vec<Txid>) -> Result<BlockSnapshot, db_error> {
         let block_height = block_header.block_height;
         let block_hash = block_header.block_hash.clone();
         let parent_block_hash = block_header.parent_block_hash.clone();
@@ -173,6 +426,8 @@ impl BlockSnapshot {
         let ops_hash = OpsHash::from_txids(txids);
         let ch = ConsensusHash::from_parent_block_data(ic, &ops_hash, block_height - 1, first_block_height, &block_header.parent_block_hash, &block_hash, burn_total)?;
 
+        let (canonical_tip_height, canonical_tip_burn_hash, canonical_tip_hash) = BlockSnapshot::resolve_canonical_stacks_tip(ic, parent_snapshot)?;
+
         debug!("SORTITION({}): NO BLOCK CHOSEN", block_height);
 
         Ok(BlockSnapshot {
@@ -192,12 +447,13 @@ impl BlockSnapshot {
             stacks_block_accepted: false,
             stacks_block_height: 0,
             arrival_index: 0,
-            canonical_stacks_tip_height: parent_snapshot.canonical_stacks_tip_height,
-            canonical_stacks_tip_hash: parent_snapshot.canonical_stacks_tip_hash.clone(),
-            canonical_stacks_tip_burn_hash: parent_snapshot.canonical_stacks_tip_burn_hash.clone()
+            canonical_stacks_tip_height: canonical_tip_height,
+            canonical_stacks_tip_hash: canonical_tip_hash,
+            canonical_stacks_tip_burn_hash: canonical_tip_burn_hash,
+            accepted_vote_tally: parent_snapshot.accepted_vote_tally + BlockSnapshot::count_accepted_votes(txids, vote_txids),
         })
     }
-    
+
 
     /// Make a block snapshot from is block's data and the previous block.
     /// This process will:
@@ -209,7 +465,7 @@ impl BlockSnapshot {
     /// All of this is rolled into the BlockSnapshot struct.
     /// 
     /// Call this *after* you store all of the block's transactions to the burn db.
-    pub fn make_snapshot<'a>(ic: &BurnDBConn<'a>, burnchain: &Burnchain, parent_snapshot: &BlockSnapshot, block_header: &BurnchainBlockHeader, burn_dist: &Vec<BurnSamplePoint>, txids: &Vec<Txid>) -> Result<BlockSnapshot, db_error> {
+    pub fn make_snapshot<'a>(ic: &BurnDBConn<'a>, burnchain: &Burnchain, parent_snapshot: &BlockSnapshot, block_header: &BurnchainBlockHeader, burn_dist: &Vec<BurnSamplePoint>, txids: &Vec<Txid>, vote_txids: &Vec<Txid>) -> Result<BlockSnapshot, db_error> {
         assert_eq!(parent_snapshot.burn_header_hash, block_header.parent_block_hash);
         assert_eq!(parent_snapshot.block_height + 1, block_header.block_height);
 
@@ -227,7 +483,7 @@ impl BlockSnapshot {
         if burn_dist.len() == 0 {
             // no burns happened
             debug!("No burns happened in block {} {:?}", block_height, &block_hash);
-            return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids);
+            return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids, &vote_txids);
         }
 
         // NOTE: this only counts burns from leader block commits and user burns that match them.
@@ -237,7 +493,7 @@ impl BlockSnapshot {
                 if total == 0 {
                     // no one burned, so no sortition
                     debug!("No transactions submitted burns in block {} {:?}", block_height, &block_hash);
-                    return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids);
+                    return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids, &vote_txids);
                 }
                 else {
                     total
@@ -246,7 +502,7 @@ impl BlockSnapshot {
             None => {
                 // overflow -- treat as 0 (no sortition)
                 warn!("Burn count exceeds maximum threshold");
-                return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids);
+                return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids, &vote_txids);
             }
         };
 
@@ -260,7 +516,7 @@ impl BlockSnapshot {
             None => {
                 // overflow.  Deny future sortitions
                 warn!("Cumulative sortition burn has overflown.  Subsequent sortitions will be denied.");
-                return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids);
+                return BlockSnapshot::make_snapshot_no_sortition(ic, parent_snapshot, block_header, first_block_height, last_burn_total, &next_sortition_hash, &txids, &vote_txids);
             }
         };
 
@@ -274,6 +530,8 @@ impl BlockSnapshot {
         let next_ops_hash = OpsHash::from_txids(&txids);
         let next_ch = ConsensusHash::from_parent_block_data(ic, &next_ops_hash, block_height - 1, first_block_height, &block_header.parent_block_hash, &block_hash, next_burn_total)?;
 
+        let (canonical_tip_height, canonical_tip_burn_hash, canonical_tip_hash) = BlockSnapshot::resolve_canonical_stacks_tip(ic, parent_snapshot)?;
+
         debug!("SORTITION({}): WINNER IS {:?} (from {:?})", block_height, &winning_block.block_header_hash, &winning_block.txid);
 
         Ok(BlockSnapshot {
@@ -293,11 +551,35 @@ impl BlockSnapshot {
             stacks_block_accepted: false,
             stacks_block_height: 0,
             arrival_index: 0,
-            canonical_stacks_tip_height: parent_snapshot.canonical_stacks_tip_height,
-            canonical_stacks_tip_hash: parent_snapshot.canonical_stacks_tip_hash.clone(),
-            canonical_stacks_tip_burn_hash: parent_snapshot.canonical_stacks_tip_burn_hash.clone(),
+            canonical_stacks_tip_height: canonical_tip_height,
+            canonical_stacks_tip_hash: canonical_tip_hash,
+            canonical_stacks_tip_burn_hash: canonical_tip_burn_hash,
+            accepted_vote_tally: parent_snapshot.accepted_vote_tally + BlockSnapshot::count_accepted_votes(txids, vote_txids),
         })
     }
+
+    /// Pull out the txids of every `VoteForAggregateKey` operation in `ops`, in the same order
+    /// `ops` is in.  This is the only code that should ever populate `make_snapshot`'s
+    /// `vote_txids` argument: without it, nothing distinguishes a vote operation from any other
+    /// accepted burnchain operation, and `accepted_vote_tally` can never advance past whatever a
+    /// caller happens to pass in by hand.
+    pub fn vote_txids_from_ops(ops: &[BlockstackOperationType]) -> Vec<Txid> {
+        ops.iter()
+            .filter(|op| match op {
+                BlockstackOperationType::VoteForAggregateKey(_) => true,
+                _ => false,
+            })
+            .map(|op| op.txid())
+            .collect()
+    }
+
+    /// Convenience wrapper over `make_snapshot` that derives `vote_txids` straight from the burn
+    /// block's own operations via `vote_txids_from_ops`, so a caller holding the full op set
+    /// can't forget to populate it (or silently pass `vec![]`).
+    pub fn make_snapshot_from_ops<'a>(ic: &BurnDBConn<'a>, burnchain: &Burnchain, parent_snapshot: &BlockSnapshot, block_header: &BurnchainBlockHeader, burn_dist: &Vec<BurnSamplePoint>, txids: &Vec<Txid>, ops: &[BlockstackOperationType]) -> Result<BlockSnapshot, db_error> {
+        let vote_txids = BlockSnapshot::vote_txids_from_ops(ops);
+        BlockSnapshot::make_snapshot(ic, burnchain, parent_snapshot, block_header, burn_dist, txids, &vote_txids)
+    }
 }
 
 #[cfg(test)]
@@ -352,7 +634,7 @@ mod test {
 
         let snapshot_no_transactions = {
             let ic = db.index_conn();
-            let sn = BlockSnapshot::make_snapshot(&ic, &burnchain, &initial_snapshot, &empty_block_header, &vec![], &vec![]).unwrap();
+            let sn = BlockSnapshot::make_snapshot(&ic, &burnchain, &initial_snapshot, &empty_block_header, &vec![], &vec![], &vec![]).unwrap();
             sn
         };
 
@@ -372,7 +654,7 @@ mod test {
 
         let snapshot_no_burns = {
             let ic = db.index_conn();
-            let sn = BlockSnapshot::make_snapshot(&ic, &burnchain, &initial_snapshot, &empty_block_header, &vec![empty_burn_point.clone()], &vec![key.txid.clone()]).unwrap();
+            let sn = BlockSnapshot::make_snapshot(&ic, &burnchain, &initial_snapshot, &empty_block_header, &vec![empty_burn_point.clone()], &vec![key.txid.clone()], &vec![]).unwrap();
             sn
         };
 
@@ -380,5 +662,78 @@ mod test {
         assert_eq!(snapshot_no_transactions.total_burn, 0);
     }
 
+    #[test]
+    fn accepted_vote_tally_advances_only_for_vote_txids_seen_in_block() {
+        let seen_txid = Txid([0x01; 32]);
+        let other_txid = Txid([0x02; 32]);
+        let unseen_vote_txid = Txid([0x03; 32]);
+
+        let txids = vec![seen_txid.clone(), other_txid.clone()];
+
+        // a vote txid that actually appears in this block's accepted ops counts...
+        let vote_txids = vec![seen_txid.clone(), unseen_vote_txid.clone()];
+        assert_eq!(BlockSnapshot::count_accepted_votes(&txids, &vote_txids), 1);
+
+        // ...and an empty vote set -- what every caller used to pass by default -- never
+        // advances the tally, which is exactly the bug `vote_txids_from_ops` fixes by giving
+        // callers a real, non-empty vote_txids to pass instead of `vec![]`.
+        assert_eq!(BlockSnapshot::count_accepted_votes(&txids, &vec![]), 0);
+    }
+
+    fn fake_snapshot_at_height(height: u64) -> BlockSnapshot {
+        let mut sn = BlockSnapshot::initial(0, &BurnchainHeaderHash([0u8; 32]), 0);
+        sn.block_height = height;
+        sn.consensus_hash = ConsensusHash([height as u8; 20]);
+        sn
+    }
+
+    #[test]
+    fn verify_fast_sync_checkpoints_accepts_matching_batch_and_rejects_tampered_one() {
+        let batch_size = FAST_SYNC_BATCH_SIZE as usize;
+        let snapshots: Vec<BlockSnapshot> = (0..batch_size as u64)
+            .map(fake_snapshot_at_height)
+            .collect();
+
+        let checkpoint = FastSyncCheckpoint {
+            start_height: 0,
+            digest: BlockSnapshot::fold_batch_digest(&snapshots),
+        };
+
+        assert!(BlockSnapshot::verify_fast_sync_checkpoints(&snapshots, &[checkpoint]).is_ok());
+
+        let mut tampered = snapshots.clone();
+        tampered[1].total_burn += 1;
+
+        let checkpoint = FastSyncCheckpoint {
+            start_height: 0,
+            digest: BlockSnapshot::fold_batch_digest(&snapshots),
+        };
+        assert!(BlockSnapshot::verify_fast_sync_checkpoints(&tampered, &[checkpoint]).is_err());
+    }
+
+    #[test]
+    fn pruned_history_compacts_old_heights_and_guards_lookups_of_unrecorded_heights() {
+        let full_history: Vec<BlockSnapshot> = (0..5).map(fake_snapshot_at_height).collect();
+
+        let mut pruned = PrunedHistory::new();
+        pruned.prune_below(&full_history, 3, |_sn| VRFSeed::initial());
+
+        // heights 0, 1, 2 got compacted away...
+        assert_eq!(pruned.pruned_floor(), Some(0));
+        let sn0 = pruned.get(0).expect("height 0 should have been pruned, not dropped");
+        assert_eq!(sn0.block_height, 0);
+        assert_eq!(pruned.get(2).expect("height 2 should have been pruned").block_height, 2);
+
+        // ...heights 3 and 4 were never pruned, so they're not here at all
+        assert!(pruned.get(3).is_err());
+        assert!(pruned.get(4).is_err());
+
+        // and a lookup of any height not recorded here reports the pruned floor
+        match pruned.get(100) {
+            Err(PrunedHistoryError { pruned_height }) => assert_eq!(pruned_height, 0),
+            Ok(_) => panic!("height 100 was never part of the pruned history"),
+        }
+    }
+
     // TODO: make snapshot with sortition
 }
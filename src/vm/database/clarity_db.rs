@@ -1,5 +1,6 @@
 use std::collections::{VecDeque, HashMap};
 use std::convert::TryFrom;
+use rusqlite;
 use rusqlite::OptionalExtension;
 
 use vm::contracts::Contract;
@@ -21,6 +22,8 @@ use vm::database::structures::{
     DataMapMetadata, DataVariableMetadata, ClaritySerializable, SimmedBlock,
     ClarityDeserializable
 };
+use serde::{Serialize, Deserialize};
+use serde_json;
 use vm::database::RollbackWrapper;
 use util::db::{DBConn, FromRow};
 use vm::costs::CostOverflowingMath;
@@ -44,79 +47,299 @@ pub enum StoreType {
     SimmedBlock = 0x10,
     SimmedBlockHeight = 0x11,
     Nonce = 0x12,
-    STXBalance = 0x13
+    STXBalance = 0x13,
+    FungibleTokenAllowance = 0x14,
+    FungibleTokenDisplayMeta = 0x15,
+    Constant = 0x16,
+    StackerDBConfig = 0x17,
+    DurableNonce = 0x18,
+    NonFungibleTokenSupply = 0x19,
+    NonFungibleTokenOwnerCount = 0x1a,
+    NonFungibleTokenOwnerIndex = 0x1b,
+    NonFungibleTokenAssetPosition = 0x1c,
+    NonFungibleTokenMetadataSchema = 0x1d,
+    NonFungibleTokenMetadata = 0x1e,
+    NonFungibleTokenFractionBinding = 0x1f,
+    StackerDBChunk = 0x20
 }
 
+/// Optional display metadata for a `define-fungible-token`, analogous to the
+/// key type tracked for NFTs. Not consensus-critical beyond the bytes stored.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FungibleTokenDisplayMetadata {
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String
+}
+
+impl ClaritySerializable for FungibleTokenDisplayMetadata {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize FungibleTokenDisplayMetadata")
+    }
+}
+
+impl ClarityDeserializable<FungibleTokenDisplayMetadata> for FungibleTokenDisplayMetadata {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize FungibleTokenDisplayMetadata")
+    }
+}
+
+/// Storage slot for an NFT's owner: `None` once the asset has been burned, so that
+/// `get_nft_owner` can still distinguish "never minted" (no entry) from "burned"
+/// (entry present, owner cleared) while reporting both as `NoSuchToken` to callers.
+#[derive(Serialize, Deserialize, Clone)]
+struct NftOwnerSlot(Option<PrincipalData>);
+
+impl ClaritySerializable for NftOwnerSlot {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize NftOwnerSlot")
+    }
+}
+
+impl ClarityDeserializable<NftOwnerSlot> for NftOwnerSlot {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize NftOwnerSlot")
+    }
+}
+
+/// A durable (offline) nonce account, modeled on Solana's durable transaction nonces: the
+/// stored `nonce_value` stands in for the account's sequential nonce so a transaction can be
+/// signed without racing it, and `authority` names the principal allowed to advance it (the
+/// owning principal by default, but delegable so e.g. a multisig coordinator can rotate it).
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DurableNonceAccount {
+    pub nonce_value: [u8; 32],
+    pub authority: PrincipalData
+}
+
+impl ClaritySerializable for DurableNonceAccount {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize DurableNonceAccount")
+    }
+}
+
+impl ClarityDeserializable<DurableNonceAccount> for DurableNonceAccount {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize DurableNonceAccount")
+    }
+}
+
+/// An NFT's position within its current owner's enumeration index, so transfers and burns
+/// can swap-remove the asset from that index in O(1) instead of scanning it.
+#[derive(Serialize, Deserialize, Clone)]
+struct NftIndexPosition {
+    owner: PrincipalData,
+    list_index: u128
+}
+
+impl ClaritySerializable for NftIndexPosition {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize NftIndexPosition")
+    }
+}
+
+impl ClarityDeserializable<NftIndexPosition> for NftIndexPosition {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize NftIndexPosition")
+    }
+}
+
+/// The `TypeSignature` that per-token metadata (token URIs, trait data, ...) must admit for
+/// a given NFT asset class, declared once alongside the class's key type.
+#[derive(Serialize, Deserialize, Clone)]
+struct NonFungibleTokenMetadataSchema {
+    metadata_type: TypeSignature
+}
+
+impl ClaritySerializable for NonFungibleTokenMetadataSchema {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize NonFungibleTokenMetadataSchema")
+    }
+}
+
+impl ClarityDeserializable<NonFungibleTokenMetadataSchema> for NonFungibleTokenMetadataSchema {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize NonFungibleTokenMetadataSchema")
+    }
+}
+
+/// Binds a fractionalized NFT to the fungible token tracking its shares. `None` once
+/// redeemed, so the binding key can be reused the next time the asset is fractionalized.
+#[derive(Serialize, Deserialize, Clone)]
+struct NftFractionBinding(Option<String>);
+
+impl ClaritySerializable for NftFractionBinding {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize NftFractionBinding")
+    }
+}
+
+impl ClarityDeserializable<NftFractionBinding> for NftFractionBinding {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize NftFractionBinding")
+    }
+}
+
+/// Default capacity of the optional in-memory read cache described below, chosen to cover
+/// the working set of metadata descriptors touched by a single block's worth of contract
+/// calls without growing unbounded.
+pub const DEFAULT_READ_CACHE_CAPACITY: usize = 4096;
+
+/// A small bounded LRU of (key -> serialized value) pairs sitting in front of
+/// `ClarityBackingStore` reads. It is purely a performance optimization: any write through
+/// `ClarityDatabase` invalidates the corresponding entry, and any rollback/block-hash
+/// transition clears the whole cache, so a cache miss always falls through to the
+/// authoritative store.
+struct ReadCache {
+    capacity: usize,
+    entries: HashMap<String, String>,
+    order: VecDeque<String>,
+}
+
+impl ReadCache {
+    fn new(capacity: usize) -> ReadCache {
+        ReadCache { capacity, entries: HashMap::new(), order: VecDeque::new() }
+    }
+
+    fn get(&mut self, key: &str) -> Option<&String> {
+        if self.entries.contains_key(key) {
+            self.touch(key);
+        }
+        self.entries.get(key)
+    }
+
+    /// Marks `key` as most recently used.
+    fn touch(&mut self, key: &str) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            if let Some(k) = self.order.remove(pos) {
+                self.order.push_back(k);
+            }
+        }
+    }
+
+    fn put(&mut self, key: String, value: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+        } else {
+            self.order.push_back(key.clone());
+            while self.order.len() > self.capacity {
+                if let Some(evicted) = self.order.pop_front() {
+                    self.entries.remove(&evicted);
+                }
+            }
+        }
+        self.entries.insert(key, value);
+    }
+
+    fn invalidate(&mut self, key: &str) {
+        self.entries.remove(key);
+        self.order.retain(|k| k != key);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// Default ceiling on the number of serialized bytes a single block's worth of contract
+/// writes may accumulate before `put_with_size` starts refusing further writes. Chosen
+/// generously above typical block write volume; embedders needing a tighter bound should
+/// use `set_block_write_length_limit`.
+pub const DEFAULT_BLOCK_WRITE_LENGTH_LIMIT: u64 = 64 * 1024 * 1024;
+
 pub struct ClarityDatabase<'a> {
     pub store: RollbackWrapper<'a>,
     headers_db: &'a dyn HeadersDB,
+    burn_state_db: &'a dyn BurnStateDB,
+    read_cache: ReadCache,
+    block_write_length: u64,
+    block_write_length_limit: u64,
 }
 
+/// All accessors return `Result<Option<T>>` rather than panicking: a missing row is
+/// `Ok(None)`, while a backing-store fault (corrupt row, SQL failure) is `Err(..)` so an
+/// embedder (e.g. an RPC node) can report it to its caller instead of aborting the process.
 pub trait HeadersDB {
-    fn get_stacks_block_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Option<BlockHeaderHash>;
-    fn get_burn_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Option<BurnchainHeaderHash>;
-    fn get_vrf_seed_for_block(&self, id_bhh: &StacksBlockId) -> Option<VRFSeed>;
-    fn get_burn_block_time_for_block(&self, id_bhh: &StacksBlockId) -> Option<u64>;
-    fn get_miner_address(&self, id_bhh: &StacksBlockId) -> Option<StacksAddress>;
+    fn get_stacks_block_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<BlockHeaderHash>>;
+    fn get_burn_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<BurnchainHeaderHash>>;
+    fn get_vrf_seed_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<VRFSeed>>;
+    fn get_burn_block_time_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<u64>>;
+    fn get_miner_address(&self, id_bhh: &StacksBlockId) -> Result<Option<StacksAddress>>;
 }
 
-fn get_stacks_header_info(conn: &DBConn, id_bhh: &StacksBlockId) -> Option<StacksHeaderInfo> {
+fn get_stacks_header_info(conn: &DBConn, id_bhh: &StacksBlockId) -> Result<Option<StacksHeaderInfo>> {
     conn.query_row("SELECT * FROM block_headers WHERE index_block_hash = ?",
                    [id_bhh].iter(),
-                   |x| StacksHeaderInfo::from_row(x).expect("Bad stacks header info in database"))
+                   |x| StacksHeaderInfo::from_row(x).map_err(|_| rusqlite::Error::InvalidQuery))
         .optional()
-        .expect("Unexpected SQL failure querying block header table")
+        .map_err(|e| InterpreterError::Expect(format!("Unexpected SQL failure querying block header table: {}", e)).into())
 }
 
-fn get_miner_info(conn: &DBConn, id_bhh: &StacksBlockId) -> Option<MinerPaymentSchedule> {
+fn get_miner_info(conn: &DBConn, id_bhh: &StacksBlockId) -> Result<Option<MinerPaymentSchedule>> {
     conn.query_row("SELECT * FROM payments WHERE index_block_hash = ? AND miner = 1",
                    [id_bhh].iter(),
-                   |x| MinerPaymentSchedule::from_row(x).expect("Bad payment info in database"))
+                   |x| MinerPaymentSchedule::from_row(x).map_err(|_| rusqlite::Error::InvalidQuery))
         .optional()
-        .expect("Unexpected SQL failure querying payment table")
+        .map_err(|e| InterpreterError::Expect(format!("Unexpected SQL failure querying payment table: {}", e)).into())
 }
 
 impl HeadersDB for DBConn {
-    fn get_stacks_block_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Option<BlockHeaderHash> {
-        get_stacks_header_info(self, id_bhh)
-            .map(|x| x.anchored_header.block_hash())
+    fn get_stacks_block_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<BlockHeaderHash>> {
+        Ok(get_stacks_header_info(self, id_bhh)?
+            .map(|x| x.anchored_header.block_hash()))
     }
-    
-    fn get_burn_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Option<BurnchainHeaderHash> {
-        get_stacks_header_info(self, id_bhh)
-            .map(|x| x.burn_header_hash)
+
+    fn get_burn_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<BurnchainHeaderHash>> {
+        Ok(get_stacks_header_info(self, id_bhh)?
+            .map(|x| x.burn_header_hash))
     }
 
-    fn get_burn_block_time_for_block(&self, id_bhh: &StacksBlockId) -> Option<u64> {
-        get_stacks_header_info(self, id_bhh)
-            .map(|x| x.burn_header_timestamp)
+    fn get_burn_block_time_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<u64>> {
+        Ok(get_stacks_header_info(self, id_bhh)?
+            .map(|x| x.burn_header_timestamp))
     }
 
-    fn get_vrf_seed_for_block(&self, id_bhh: &StacksBlockId) -> Option<VRFSeed> {
-        get_stacks_header_info(self, id_bhh)
-            .map(|x| VRFSeed::from_proof(&x.anchored_header.proof))
+    fn get_vrf_seed_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<VRFSeed>> {
+        Ok(get_stacks_header_info(self, id_bhh)?
+            .map(|x| VRFSeed::from_proof(&x.anchored_header.proof)))
     }
 
-    fn get_miner_address(&self, id_bhh: &StacksBlockId)  -> Option<StacksAddress> {
-        get_miner_info(self, id_bhh)
-            .map(|x| x.address)
+    fn get_miner_address(&self, id_bhh: &StacksBlockId)  -> Result<Option<StacksAddress>> {
+        Ok(get_miner_info(self, id_bhh)?
+            .map(|x| x.address))
     }
 }
 
 impl HeadersDB for &dyn HeadersDB {
-    fn get_stacks_block_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Option<BlockHeaderHash> {
+    fn get_stacks_block_header_hash_for_block(&self, id_bhh: &StacksBlockId) -> Result<Option<BlockHeaderHash>> {
         (*self).get_stacks_block_header_hash_for_block(id_bhh)
     }
-    fn get_burn_header_hash_for_block(&self, bhh: &StacksBlockId) -> Option<BurnchainHeaderHash> {
+    fn get_burn_header_hash_for_block(&self, bhh: &StacksBlockId) -> Result<Option<BurnchainHeaderHash>> {
         (*self).get_burn_header_hash_for_block(bhh)
     }
-    fn get_vrf_seed_for_block(&self, bhh: &StacksBlockId) -> Option<VRFSeed> {
+    fn get_vrf_seed_for_block(&self, bhh: &StacksBlockId) -> Result<Option<VRFSeed>> {
         (*self).get_vrf_seed_for_block(bhh)
     }
-    fn get_burn_block_time_for_block(&self, bhh: &StacksBlockId) -> Option<u64> {
+    fn get_burn_block_time_for_block(&self, bhh: &StacksBlockId) -> Result<Option<u64>> {
         (*self).get_burn_block_time_for_block(bhh)
     }
-    fn get_miner_address(&self, bhh: &StacksBlockId)  -> Option<StacksAddress> {
+    fn get_miner_address(&self, bhh: &StacksBlockId)  -> Result<Option<StacksAddress>> {
         (*self).get_miner_address(bhh)
     }
 }
@@ -126,39 +349,88 @@ pub struct NullHeadersDB {}
 pub const NULL_HEADER_DB: NullHeadersDB = NullHeadersDB {};
 
 impl HeadersDB for NullHeadersDB {
-    fn get_burn_header_hash_for_block(&self, _bhh: &StacksBlockId) -> Option<BurnchainHeaderHash> {
-        None
+    fn get_burn_header_hash_for_block(&self, _bhh: &StacksBlockId) -> Result<Option<BurnchainHeaderHash>> {
+        Ok(None)
     }
-    fn get_vrf_seed_for_block(&self, _bhh: &StacksBlockId) -> Option<VRFSeed> {
-        None
+    fn get_vrf_seed_for_block(&self, _bhh: &StacksBlockId) -> Result<Option<VRFSeed>> {
+        Ok(None)
     }
-    fn get_stacks_block_header_hash_for_block(&self, _id_bhh: &StacksBlockId) -> Option<BlockHeaderHash> {
-        None
+    fn get_stacks_block_header_hash_for_block(&self, _id_bhh: &StacksBlockId) -> Result<Option<BlockHeaderHash>> {
+        Ok(None)
     }
-    fn get_burn_block_time_for_block(&self, _id_bhh: &StacksBlockId) -> Option<u64> {
-        None
+    fn get_burn_block_time_for_block(&self, _id_bhh: &StacksBlockId) -> Result<Option<u64>> {
+        Ok(None)
+    }
+    fn get_miner_address(&self, _id_bhh: &StacksBlockId)  -> Result<Option<StacksAddress>> {
+        Ok(None)
     }
-    fn get_miner_address(&self, _id_bhh: &StacksBlockId)  -> Option<StacksAddress> {
+}
+
+/// Maps a Stacks block height to the burnchain (e.g. Bitcoin) block height its tenure was
+/// produced against. Kept separate from `HeadersDB` since it is sourced from the burnchain
+/// indexer rather than the Stacks header table, mirroring how `ClarityDatabase` holds the two
+/// as distinct read-only handles.
+pub trait BurnStateDB {
+    fn get_burn_block_height(&self, stacks_block_height: u32) -> Option<u32>;
+}
+
+impl BurnStateDB for &dyn BurnStateDB {
+    fn get_burn_block_height(&self, stacks_block_height: u32) -> Option<u32> {
+        (*self).get_burn_block_height(stacks_block_height)
+    }
+}
+
+pub struct NullBurnStateDB {}
+
+pub const NULL_BURN_STATE_DB: NullBurnStateDB = NullBurnStateDB {};
+
+impl BurnStateDB for NullBurnStateDB {
+    fn get_burn_block_height(&self, _stacks_block_height: u32) -> Option<u32> {
         None
     }
 }
 
 impl <'a> ClarityDatabase <'a> {
-    pub fn new(store: &'a mut dyn ClarityBackingStore, headers_db: &'a dyn HeadersDB) -> ClarityDatabase<'a> {
+    pub fn new(store: &'a mut dyn ClarityBackingStore, headers_db: &'a dyn HeadersDB, burn_state_db: &'a dyn BurnStateDB) -> ClarityDatabase<'a> {
+        ClarityDatabase::new_with_cache_capacity(store, headers_db, burn_state_db, DEFAULT_READ_CACHE_CAPACITY)
+    }
+
+    /// Like `new`, but with an explicit bound on the in-memory read cache's entry count.
+    /// Pass `0` to disable the cache entirely.
+    pub fn new_with_cache_capacity(store: &'a mut dyn ClarityBackingStore, headers_db: &'a dyn HeadersDB, burn_state_db: &'a dyn BurnStateDB, cache_capacity: usize) -> ClarityDatabase<'a> {
         ClarityDatabase {
             store: RollbackWrapper::new(store),
-            headers_db
+            headers_db,
+            burn_state_db,
+            read_cache: ReadCache::new(cache_capacity),
+            block_write_length: 0,
+            block_write_length_limit: DEFAULT_BLOCK_WRITE_LENGTH_LIMIT,
+        }
+    }
+
+    pub fn new_with_rollback_wrapper(store: RollbackWrapper<'a>, headers_db: &'a dyn HeadersDB, burn_state_db: &'a dyn BurnStateDB) -> ClarityDatabase<'a> {
+        ClarityDatabase {
+            store, headers_db, burn_state_db,
+            read_cache: ReadCache::new(DEFAULT_READ_CACHE_CAPACITY),
+            block_write_length: 0,
+            block_write_length_limit: DEFAULT_BLOCK_WRITE_LENGTH_LIMIT,
         }
     }
 
-    pub fn new_with_rollback_wrapper(store: RollbackWrapper<'a>, headers_db: &'a dyn HeadersDB) -> ClarityDatabase<'a> {
-        ClarityDatabase { store, headers_db }
+    /// Configure the per-block write-length ceiling enforced by `put_with_size`.
+    pub fn set_block_write_length_limit(&mut self, limit: u64) {
+        self.block_write_length_limit = limit;
+    }
+
+    pub fn get_current_block_write_length(&self) -> u64 {
+        self.block_write_length
     }
 
     pub fn initialize(&mut self) {
     }
 
     pub fn begin(&mut self) {
+        self.block_write_length = 0;
         self.store.nest();
     }
 
@@ -168,18 +440,44 @@ impl <'a> ClarityDatabase <'a> {
 
     pub fn roll_back(&mut self) {
         self.store.rollback();
+        self.read_cache.clear();
+        self.block_write_length = 0;
     }
 
     pub fn set_block_hash(&mut self, bhh: StacksBlockId) -> Result<StacksBlockId> {
+        self.read_cache.clear();
         self.store.set_block_hash(bhh)
     }
 
     pub fn put <T: ClaritySerializable> (&mut self, key: &str, value: &T) {
-        self.store.put(&key, &value.serialize());
+        let serialized = value.serialize();
+        self.store.put(&key, &serialized);
+        self.read_cache.invalidate(key);
+    }
+
+    /// Like `put`, but returns the serialized byte length of `value` and accumulates
+    /// it into the current block's write counter, enforcing `block_write_length_limit`.
+    pub fn put_with_size <T: ClaritySerializable> (&mut self, key: &str, value: &T) -> Result<u64> {
+        let serialized = value.serialize();
+        let written = serialized.len() as u64;
+        let new_total = self.block_write_length.checked_add(written)
+            .ok_or(InterpreterError::Expect("Block write length overflowed u64".into()))?;
+        if new_total > self.block_write_length_limit {
+            return Err(RuntimeErrorType::WriteLengthOverflow(self.block_write_length_limit).into());
+        }
+        self.block_write_length = new_total;
+        self.store.put(&key, &serialized);
+        self.read_cache.invalidate(key);
+        Ok(written)
     }
 
-    fn get <T> (&mut self, key: &str) -> Option<T> where T: ClarityDeserializable<T> {
-        self.store.get::<T>(key)
+    fn get <T> (&mut self, key: &str) -> Option<T> where T: ClarityDeserializable<T> + ClaritySerializable {
+        if let Some(cached) = self.read_cache.get(key) {
+            return Some(T::deserialize(cached));
+        }
+        let value = self.store.get::<T>(key)?;
+        self.read_cache.put(key.to_string(), value.serialize());
+        Some(value)
     }
 
     pub fn get_value (&mut self, key: &str, expected: &TypeSignature) -> Option<Value> {
@@ -223,18 +521,34 @@ impl <'a> ClarityDatabase <'a> {
         self.fetch_metadata(contract_identifier, &key).ok().flatten()
     }
 
+    fn metadata_cache_key(contract_identifier: &QualifiedContractIdentifier, key: &str) -> String {
+        format!("meta::{}::{}", contract_identifier, key)
+    }
+
     fn insert_metadata <T: ClaritySerializable> (&mut self, contract_identifier: &QualifiedContractIdentifier, key: &str, data: &T) {
         if self.store.has_metadata_entry(contract_identifier, key) {
             panic!("Metadata entry '{}' already exists for contract: {}", key, contract_identifier);
         } else {
-            self.store.insert_metadata(contract_identifier, key, &data.serialize());
+            let serialized = data.serialize();
+            self.store.insert_metadata(contract_identifier, key, &serialized);
+            self.read_cache.invalidate(&ClarityDatabase::metadata_cache_key(contract_identifier, key));
         }
     }
 
     fn fetch_metadata <T> (&mut self, contract_identifier: &QualifiedContractIdentifier, key: &str) -> Result<Option<T>>
-    where T: ClarityDeserializable<T> {
-        self.store.get_metadata(contract_identifier, key)
-            .map(|x_opt| x_opt.map(|x| T::deserialize(&x)))
+    where T: ClarityDeserializable<T> + ClaritySerializable {
+        let cache_key = ClarityDatabase::metadata_cache_key(contract_identifier, key);
+        if let Some(cached) = self.read_cache.get(&cache_key) {
+            return Ok(Some(T::deserialize(cached)));
+        }
+
+        let result = self.store.get_metadata(contract_identifier, key)
+            .map(|x_opt| x_opt.map(|x| T::deserialize(&x)))?;
+
+        if let Some(ref value) = result {
+            self.read_cache.put(cache_key, value.serialize());
+        }
+        Ok(result)
     }
 
     pub fn get_contract_size(&mut self, contract_identifier: &QualifiedContractIdentifier) -> Result<u64> {
@@ -297,35 +611,75 @@ impl <'a> ClarityDatabase <'a> {
         self.store.get_current_block_height()
     }
 
-    pub fn get_block_header_hash(&mut self, block_height: u32) -> BlockHeaderHash {
+    /// Resolve the current Stacks block height to its underlying burnchain (e.g. Bitcoin)
+    /// height, for Clarity natives (`burn-block-height`) and time-locks that gate on burnchain
+    /// progression rather than Stacks block progression.
+    pub fn get_current_burnchain_block_height(&mut self) -> Result<u32> {
+        let current_height = self.get_current_block_height();
+        self.burn_state_db.get_burn_block_height(current_height)
+            .ok_or_else(|| InterpreterError::Expect("Failed to get burnchain height for current block".into()).into())
+    }
+
+    /// Consensus-critical invariant: a block header hash that *should* be available from
+    /// the headers DB (i.e. one for a block height below the current chain tip) was not
+    /// found, which indicates corruption. It is a recoverable `Err` rather than a `panic!`:
+    /// an `InterpreterError::Expect` with the message "Failed to get block data.", the same
+    /// variant `get_stacks_header_info` uses for SQL faults, so only the message tells the
+    /// two apart.
+    fn require_block_data<T>(found: Result<Option<T>>) -> Result<T> {
+        found?.ok_or_else(|| InterpreterError::Expect("Failed to get block data.".into()).into())
+    }
+
+    pub fn get_block_header_hash(&mut self, block_height: u32) -> Result<BlockHeaderHash> {
         let id_bhh = self.get_index_block_header_hash(block_height);
-        self.headers_db.get_stacks_block_header_hash_for_block(&id_bhh)
-            .expect("Failed to get block data.")
+        Self::require_block_data(self.headers_db.get_stacks_block_header_hash_for_block(&id_bhh))
     }
 
-    pub fn get_block_time(&mut self, block_height: u32) -> u64 {
+    pub fn get_block_time(&mut self, block_height: u32) -> Result<u64> {
         let id_bhh = self.get_index_block_header_hash(block_height);
-        self.headers_db.get_burn_block_time_for_block(&id_bhh)
-            .expect("Failed to get block data.")
+        Self::require_block_data(self.headers_db.get_burn_block_time_for_block(&id_bhh))
     }
 
-    pub fn get_burnchain_block_header_hash(&mut self, block_height: u32) -> BurnchainHeaderHash {
+    pub fn get_burnchain_block_header_hash(&mut self, block_height: u32) -> Result<BurnchainHeaderHash> {
         let id_bhh = self.get_index_block_header_hash(block_height);
-        self.headers_db.get_burn_header_hash_for_block(&id_bhh)
-            .expect("Failed to get block data.")
+        Self::require_block_data(self.headers_db.get_burn_header_hash_for_block(&id_bhh))
     }
 
-    pub fn get_block_vrf_seed(&mut self, block_height: u32) -> VRFSeed {
+    pub fn get_block_vrf_seed(&mut self, block_height: u32) -> Result<VRFSeed> {
         let id_bhh = self.get_index_block_header_hash(block_height);
-        self.headers_db.get_vrf_seed_for_block(&id_bhh)
-            .expect("Failed to get block data.")
+        Self::require_block_data(self.headers_db.get_vrf_seed_for_block(&id_bhh))
     }
 
-    pub fn get_miner_address(&mut self, block_height: u32) -> StandardPrincipalData {
+    pub fn get_miner_address(&mut self, block_height: u32) -> Result<StandardPrincipalData> {
         let id_bhh = self.get_index_block_header_hash(block_height);
-        self.headers_db.get_miner_address(&id_bhh)
-            .expect("Failed to get block data.")
-            .into()
+        Ok(Self::require_block_data(self.headers_db.get_miner_address(&id_bhh))?.into())
+    }
+
+    /// Median-time-past over the `MEDIAN_TIME_PAST_WINDOW` burn blocks ending at
+    /// `block_height` (fewer near genesis), computed the BIP113 way: collect the window's
+    /// burn timestamps, sort them, and take the (lower-)middle element. Unlike a raw block
+    /// timestamp, this is monotonically non-decreasing block-over-block, which makes it
+    /// safe to use for relative-timelock conditions.
+    pub fn get_burn_block_median_time_past(&mut self, block_height: u32) -> Result<u64> {
+        const MEDIAN_TIME_PAST_WINDOW: u32 = 11;
+
+        let window_start = block_height.saturating_sub(MEDIAN_TIME_PAST_WINDOW - 1);
+        let mut timestamps: Vec<u64> = Vec::with_capacity(MEDIAN_TIME_PAST_WINDOW as usize);
+        for height in window_start..=block_height {
+            timestamps.push(self.get_block_time(height)?);
+        }
+
+        timestamps.sort_unstable();
+        Ok(timestamps[(timestamps.len() - 1) / 2])
+    }
+
+    /// Companion to `get_burn_block_median_time_past` for Stacks block timestamps, for
+    /// callers (e.g. a future Clarity native) that want monotonic time derived from the
+    /// Stacks chain rather than the burnchain. Stacks blocks do not currently carry their
+    /// own timestamp in `HeadersDB`, so this reuses the burn timestamp of the Stacks
+    /// block's sortition as a stand-in.
+    pub fn get_stacks_block_median_time_past(&mut self, block_height: u32) -> Result<u64> {
+        self.get_burn_block_median_time_past(block_height)
     }
 }
 
@@ -363,7 +717,7 @@ impl <'a> ClarityDatabase <'a> {
 
         let key = ClarityDatabase::make_key_for_trip(contract_identifier, StoreType::Variable, variable_name);
 
-        self.put(&key, &value);
+        self.put_with_size(&key, &value)?;
 
         return Ok(Value::Bool(true))
     }
@@ -382,6 +736,166 @@ impl <'a> ClarityDatabase <'a> {
     }
 }
 
+/// Metadata persisted for each top-level `define-constant`: the already-evaluated `Value`
+/// plus its `TypeSignature`, so a constant can be read back without re-running the
+/// contract's initialization code.
+#[derive(Serialize, Deserialize, Clone)]
+struct ConstantMetadata {
+    value_type: TypeSignature,
+    value: Value,
+}
+
+impl ClaritySerializable for ConstantMetadata {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize ConstantMetadata")
+    }
+}
+
+impl ClarityDeserializable<ConstantMetadata> for ConstantMetadata {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize ConstantMetadata")
+    }
+}
+
+// Constant Functions...
+impl <'a> ClarityDatabase <'a> {
+    pub fn create_constant(&mut self, contract_identifier: &QualifiedContractIdentifier, const_name: &str, value: Value, value_type: TypeSignature) {
+        let data = ConstantMetadata { value_type, value };
+        let key = ClarityDatabase::make_metadata_key(StoreType::Constant, const_name);
+
+        self.insert_metadata(contract_identifier, &key, &data)
+    }
+
+    pub fn lookup_constant(&mut self, contract_identifier: &QualifiedContractIdentifier, const_name: &str) -> Result<Value> {
+        let key = ClarityDatabase::make_metadata_key(StoreType::Constant, const_name);
+
+        let data: ConstantMetadata = map_no_contract_as_none(
+            self.fetch_metadata(contract_identifier, &key))?
+            .ok_or(CheckErrors::NoSuchConstant(const_name.to_string()))?;
+
+        Ok(data.value)
+    }
+
+    /// External-facing counterpart to `lookup_constant`: the same single metadata read,
+    /// exposed under the name query tooling and RPC layers reach for when they want a
+    /// `define-constant` value without simulating a read-only function call.
+    pub fn get_constant(&mut self, contract_identifier: &QualifiedContractIdentifier, const_name: &str) -> Result<Value> {
+        self.lookup_constant(contract_identifier, const_name)
+    }
+}
+
+/// A single replica's slot allocation within a contract-declared StackerDB instance: how
+/// many off-chain chunks it may write, and at what signer principal.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StackerDBSlot {
+    pub signer: PrincipalData,
+    pub num_slots: u32,
+}
+
+/// The consensus-side configuration of a contract-controlled off-chain StackerDB: the
+/// shape of the replicated store (slot count/size bounds) and which principals may write
+/// to it. The node's networking layer consumes this to decide which chunks to host.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StackerDBConfig {
+    pub chunk_size: u32,
+    pub max_writes: u32,
+    pub slots: Vec<StackerDBSlot>,
+}
+
+impl ClaritySerializable for StackerDBConfig {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize StackerDBConfig")
+    }
+}
+
+impl ClarityDeserializable<StackerDBConfig> for StackerDBConfig {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize StackerDBConfig")
+    }
+}
+
+// StackerDB configuration
+impl <'a> ClarityDatabase <'a> {
+    pub fn set_stackerdb_config(&mut self, contract_identifier: &QualifiedContractIdentifier, config: StackerDBConfig) {
+        let key = ClarityDatabase::make_metadata_key(StoreType::StackerDBConfig, "config");
+        self.insert_metadata(contract_identifier, &key, &config)
+    }
+
+    pub fn get_stackerdb_config(&mut self, contract_identifier: &QualifiedContractIdentifier) -> Result<StackerDBConfig> {
+        let key = ClarityDatabase::make_metadata_key(StoreType::StackerDBConfig, "config");
+        map_no_contract_as_none(
+            self.fetch_metadata(contract_identifier, &key))?
+            .ok_or(CheckErrors::NoSuchContract(contract_identifier.to_string()).into())
+    }
+
+    /// Returns the `(signer, slot_count)` list derived from this contract's declared
+    /// StackerDB config, for the node's networking layer to decide which off-chain chunks
+    /// to host for subscribed replicas.
+    pub fn get_stackerdb_signer_slots(&mut self, contract_identifier: &QualifiedContractIdentifier) -> Result<Vec<(PrincipalData, u32)>> {
+        let config = self.get_stackerdb_config(contract_identifier)?;
+        Ok(config.slots.into_iter().map(|slot| (slot.signer, slot.num_slots)).collect())
+    }
+
+    fn make_key_for_stackerdb_chunk(contract_identifier: &QualifiedContractIdentifier, slot_id: u32) -> String {
+        ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::StackerDBChunk, "slot", slot_id.to_string())
+    }
+
+    /// Persists the latest accepted chunk for `slot_id`, so it survives a restart and is
+    /// queryable like any other piece of `ClarityDatabase` state rather than living only in
+    /// whatever `StackerDBBackingStore` happens to be in memory at the time.
+    pub fn set_stackerdb_chunk(&mut self, contract_identifier: &QualifiedContractIdentifier, slot_id: u32, version: u32, data: &[u8]) {
+        let key = ClarityDatabase::make_key_for_stackerdb_chunk(contract_identifier, slot_id);
+        let record = StackerDBChunkRecord { version, data: stackerdb_chunk_to_hex(data) };
+        self.put(&key, &record);
+    }
+
+    /// Reads back whatever chunk `set_stackerdb_chunk` last persisted for `slot_id`, or `None`
+    /// if nothing has ever been written to it.
+    pub fn get_stackerdb_chunk(&mut self, contract_identifier: &QualifiedContractIdentifier, slot_id: u32) -> Option<(u32, Vec<u8>)> {
+        let key = ClarityDatabase::make_key_for_stackerdb_chunk(contract_identifier, slot_id);
+        let record: StackerDBChunkRecord = self.get(&key)?;
+        Some((record.version, stackerdb_chunk_from_hex(&record.data)))
+    }
+}
+
+fn stackerdb_chunk_to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn stackerdb_chunk_from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("stored StackerDB chunk was not valid hex"))
+        .collect()
+}
+
+/// The durable, on-disk form of a single StackerDB slot's latest accepted chunk: its
+/// monotonically increasing version, plus the chunk bytes (hex-encoded, since the backing
+/// store's value type is a plain string).
+#[derive(Serialize, Deserialize, Clone)]
+struct StackerDBChunkRecord {
+    version: u32,
+    data: String,
+}
+
+impl ClaritySerializable for StackerDBChunkRecord {
+    fn serialize(&self) -> String {
+        serde_json::to_string(self)
+            .expect("FAIL: Failed to serialize StackerDBChunkRecord")
+    }
+}
+
+impl ClarityDeserializable<StackerDBChunkRecord> for StackerDBChunkRecord {
+    fn deserialize(json: &str) -> Self {
+        serde_json::from_str(json)
+            .expect("FAIL: Failed to deserialize StackerDBChunkRecord")
+    }
+}
+
 // Data Map Functions
 impl <'a> ClarityDatabase <'a> {
     pub fn create_map(&mut self, contract_identifier: &QualifiedContractIdentifier, map_name: &str, key_type: TupleTypeSignature, value_type: TupleTypeSignature) {
@@ -456,7 +970,7 @@ impl <'a> ClarityDatabase <'a> {
         }
 
         let placed_value = Value::some(value)?;
-        self.put(&key, &placed_value);
+        self.put_with_size(&key, &placed_value)?;
 
         return Ok(Value::Bool(true))
     }
@@ -473,7 +987,7 @@ impl <'a> ClarityDatabase <'a> {
             return Ok(Value::Bool(false))
         }
 
-        self.put(&key, &(Value::none()));
+        self.put_with_size(&key, &(Value::none()))?;
 
         return Ok(Value::Bool(true))
     }
@@ -482,7 +996,7 @@ impl <'a> ClarityDatabase <'a> {
 // Asset Functions
 
 impl <'a> ClarityDatabase <'a> {
-    pub fn create_fungible_token(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, total_supply: &Option<u128>) {
+    pub fn create_fungible_token(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, total_supply: &Option<u128>) -> Result<()> {
         let data = FungibleTokenMetadata { total_supply: total_supply.clone() };
 
         let key = ClarityDatabase::make_metadata_key(StoreType::FungibleTokenMeta, token_name);
@@ -491,8 +1005,9 @@ impl <'a> ClarityDatabase <'a> {
         // total supply _is_ included in the consensus hash
         if total_supply.is_some() {
             let supply_key = ClarityDatabase::make_key_for_trip(contract_identifier, StoreType::CirculatingSupply, token_name);
-            self.put(&supply_key, &(0 as u128));
+            self.put_with_size(&supply_key, &(0 as u128))?;
         }
+        Ok(())
     }
 
     fn load_ft(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str) -> Result<FungibleTokenMetadata> {
@@ -503,6 +1018,20 @@ impl <'a> ClarityDatabase <'a> {
             .ok_or(CheckErrors::NoSuchFT(token_name.to_string()).into())
     }
 
+    pub fn set_ft_metadata(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, metadata: &FungibleTokenDisplayMetadata) {
+        let key = ClarityDatabase::make_metadata_key(StoreType::FungibleTokenDisplayMeta, token_name);
+        self.insert_metadata(contract_identifier, &key, metadata);
+    }
+
+    pub fn get_ft_metadata(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str) -> Result<FungibleTokenDisplayMetadata> {
+        self.load_ft(contract_identifier, token_name)?;
+
+        let key = ClarityDatabase::make_metadata_key(StoreType::FungibleTokenDisplayMeta, token_name);
+        map_no_contract_as_none(
+            self.fetch_metadata(contract_identifier, &key))?
+            .ok_or(CheckErrors::NoSuchFT(token_name.to_string()).into())
+    }
+
     pub fn create_non_fungible_token(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, key_type: &TypeSignature) {
         let data = NonFungibleTokenMetadata { key_type: key_type.clone() };
         let key = ClarityDatabase::make_metadata_key(StoreType::NonFungibleTokenMeta, token_name);
@@ -539,6 +1068,76 @@ impl <'a> ClarityDatabase <'a> {
         }
     }
 
+    pub fn checked_decrease_token_supply(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, amount: u128) -> Result<()> {
+        let descriptor = self.load_ft(contract_identifier, token_name)?;
+
+        if descriptor.total_supply.is_some() {
+            let key = ClarityDatabase::make_key_for_trip(contract_identifier, StoreType::CirculatingSupply, token_name);
+            let current_supply: u128 = self.get(&key)
+                .expect("ERROR: Clarity VM failed to track token supply.");
+
+            let new_supply = current_supply.checked_sub(amount)
+                .ok_or(RuntimeErrorType::ArithmeticUnderflow)?;
+
+            self.put(&key, &new_supply);
+        }
+        Ok(())
+    }
+
+    pub fn get_token_supply(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str) -> Result<u128> {
+        let descriptor = self.load_ft(contract_identifier, token_name)?;
+
+        if descriptor.total_supply.is_some() {
+            let key = ClarityDatabase::make_key_for_trip(contract_identifier, StoreType::CirculatingSupply, token_name);
+            Ok(self.get(&key)
+                .expect("ERROR: Clarity VM failed to track token supply."))
+        } else {
+            Ok(0)
+        }
+    }
+
+    pub fn ft_exists(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str) -> bool {
+        self.load_ft(contract_identifier, token_name).is_ok()
+    }
+
+    /// Equivalent to `get_token_supply`, named to match this group's `ft_`-prefixed accessors.
+    pub fn get_ft_supply(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str) -> Result<u128> {
+        self.get_token_supply(contract_identifier, token_name)
+    }
+
+    /// The immutable supply cap a token was defined with, if any. Mints that would push
+    /// `get_ft_supply` past this cap are rejected by `checked_increase_token_supply`.
+    pub fn get_ft_supply_cap(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str) -> Result<Option<u128>> {
+        let descriptor = self.load_ft(contract_identifier, token_name)?;
+        Ok(descriptor.total_supply)
+    }
+
+    /// Atomically credits `principal`'s balance and, for a token defined with a supply cap,
+    /// its circulating supply, rejecting the mint outright (rather than applying a partial
+    /// update) if it would exceed the token's supply cap or overflow a principal's balance.
+    /// A token defined without a cap has no circulating-supply counter; only the balance changes.
+    pub fn ft_mint(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, principal: &PrincipalData, amount: u128) -> Result<()> {
+        let balance = self.get_ft_balance(contract_identifier, token_name, principal)?;
+        let new_balance = balance.checked_add(amount).ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+
+        self.checked_increase_token_supply(contract_identifier, token_name, amount)?;
+        self.set_ft_balance(contract_identifier, token_name, principal, new_balance)
+    }
+
+    /// Atomically debits `principal`'s balance and, for a token defined with a supply cap,
+    /// its circulating supply; for a token defined without a cap only the balance changes.
+    pub fn ft_burn(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, principal: &PrincipalData, amount: u128) -> Result<()> {
+        let balance = self.get_ft_balance(contract_identifier, token_name, principal)?;
+        let new_balance = balance.checked_sub(amount).ok_or(RuntimeErrorType::ArithmeticUnderflow)?;
+
+        self.checked_decrease_token_supply(contract_identifier, token_name, amount)?;
+        self.set_ft_balance(contract_identifier, token_name, principal, new_balance)
+    }
+
+    pub fn nft_exists(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> bool {
+        self.get_nft_owner(contract_identifier, asset_name, asset).is_ok()
+    }
+
     pub fn get_ft_balance(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, principal: &PrincipalData) -> Result<u128> {
         self.load_ft(contract_identifier, token_name)?;
 
@@ -558,6 +1157,31 @@ impl <'a> ClarityDatabase <'a> {
         Ok(())
     }
 
+    fn make_key_for_ft_allowance(owner: &PrincipalData, spender: &PrincipalData) -> String {
+        format!("{}->{}", owner.serialize(), spender.serialize())
+    }
+
+    pub fn get_ft_allowance(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, owner: &PrincipalData, spender: &PrincipalData) -> Result<u128> {
+        self.load_ft(contract_identifier, token_name)?;
+
+        let key_value = ClarityDatabase::make_key_for_ft_allowance(owner, spender);
+        let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::FungibleTokenAllowance, token_name, key_value);
+
+        let result = self.get(&key);
+        match result {
+            None => Ok(0),
+            Some(allowance) => Ok(allowance)
+        }
+    }
+
+    pub fn set_ft_allowance(&mut self, contract_identifier: &QualifiedContractIdentifier, token_name: &str, owner: &PrincipalData, spender: &PrincipalData, allowance: u128) -> Result<()> {
+        let key_value = ClarityDatabase::make_key_for_ft_allowance(owner, spender);
+        let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::FungibleTokenAllowance, token_name, key_value);
+        self.put(&key, &allowance);
+
+        Ok(())
+    }
+
     pub fn get_nft_owner(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> Result<PrincipalData> {
         let descriptor = self.load_nft(contract_identifier, asset_name)?;
         if !descriptor.key_type.admits(asset) {
@@ -566,8 +1190,10 @@ impl <'a> ClarityDatabase <'a> {
 
         let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleToken, asset_name, asset.serialize());
 
-        let result = self.get(&key);
-        result.ok_or(RuntimeErrorType::NoSuchToken.into())
+        let result: Option<NftOwnerSlot> = self.get(&key);
+        result
+            .and_then(|slot| slot.0)
+            .ok_or(RuntimeErrorType::NoSuchToken.into())
     }
 
     pub fn get_nft_key_type(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str) -> Result<TypeSignature> {
@@ -580,10 +1206,257 @@ impl <'a> ClarityDatabase <'a> {
         if !descriptor.key_type.admits(asset) {
             return Err(CheckErrors::TypeValueError(descriptor.key_type, (*asset).clone()).into())
         }
+        if self.is_nft_fractionalized(contract_identifier, asset_name, asset)? {
+            return Err(RuntimeErrorType::NftLockedForFractionalization.into())
+        }
 
         let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleToken, asset_name, asset.serialize());
+        let prior_owner = self.get::<NftOwnerSlot>(&key).and_then(|slot| slot.0);
+
+        self.put(&key, &NftOwnerSlot(Some(principal.clone())));
+
+        match prior_owner {
+            None => {
+                // first mint: bump the class supply, then index under the new owner
+                self.increment_nft_supply(contract_identifier, asset_name)?;
+                self.index_nft_for_owner(contract_identifier, asset_name, principal, asset)?;
+            }
+            Some(ref old_owner) if old_owner != principal => {
+                self.unindex_nft_for_owner(contract_identifier, asset_name, old_owner, asset)?;
+                self.index_nft_for_owner(contract_identifier, asset_name, principal, asset)?;
+            }
+            Some(_) => {
+                // re-asserting the existing owner: supply and index are already correct
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn burn_nft_owner(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> Result<()> {
+        let descriptor = self.load_nft(contract_identifier, asset_name)?;
+        if !descriptor.key_type.admits(asset) {
+            return Err(CheckErrors::TypeValueError(descriptor.key_type, (*asset).clone()).into())
+        }
+        if self.is_nft_fractionalized(contract_identifier, asset_name, asset)? {
+            return Err(RuntimeErrorType::NftLockedForFractionalization.into())
+        }
+
+        let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleToken, asset_name, asset.serialize());
+        let prior_owner = self.get::<NftOwnerSlot>(&key).and_then(|slot| slot.0);
+
+        self.put(&key, &NftOwnerSlot(None));
+
+        if let Some(ref old_owner) = prior_owner {
+            self.unindex_nft_for_owner(contract_identifier, asset_name, old_owner, asset)?;
+            self.decrement_nft_supply(contract_identifier, asset_name)?;
+        }
+
+        Ok(())
+    }
+
+    fn make_key_for_nft_supply(contract_identifier: &QualifiedContractIdentifier, asset_name: &str) -> String {
+        ClarityDatabase::make_key_for_trip(contract_identifier, StoreType::NonFungibleTokenSupply, asset_name)
+    }
+
+    fn make_key_for_nft_balance(contract_identifier: &QualifiedContractIdentifier, asset_name: &str, principal: &PrincipalData) -> String {
+        ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleTokenOwnerCount, asset_name, principal.serialize())
+    }
 
-        self.put(&key, principal);
+    fn make_key_for_nft_owner_index(contract_identifier: &QualifiedContractIdentifier, asset_name: &str, principal: &PrincipalData, list_index: u128) -> String {
+        ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleTokenOwnerIndex, asset_name, format!("{}::{}", principal.serialize(), list_index))
+    }
+
+    fn make_key_for_nft_asset_position(contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> String {
+        ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleTokenAssetPosition, asset_name, asset.serialize())
+    }
+
+    pub fn get_nft_supply(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str) -> Result<u128> {
+        self.load_nft(contract_identifier, asset_name)?;
+        let key = ClarityDatabase::make_key_for_nft_supply(contract_identifier, asset_name);
+        Ok(self.get(&key).unwrap_or(0))
+    }
+
+    fn increment_nft_supply(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str) -> Result<()> {
+        let key = ClarityDatabase::make_key_for_nft_supply(contract_identifier, asset_name);
+        let supply: u128 = self.get(&key).unwrap_or(0);
+        let new_supply = supply.checked_add(1).ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+        self.put(&key, &new_supply);
+        Ok(())
+    }
+
+    fn decrement_nft_supply(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str) -> Result<()> {
+        let key = ClarityDatabase::make_key_for_nft_supply(contract_identifier, asset_name);
+        let supply: u128 = self.get(&key).unwrap_or(0);
+        let new_supply = supply.checked_sub(1).ok_or(RuntimeErrorType::ArithmeticUnderflow)?;
+        self.put(&key, &new_supply);
+        Ok(())
+    }
+
+    pub fn get_nft_balance(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, principal: &PrincipalData) -> Result<u128> {
+        self.load_nft(contract_identifier, asset_name)?;
+        let key = ClarityDatabase::make_key_for_nft_balance(contract_identifier, asset_name, principal);
+        Ok(self.get(&key).unwrap_or(0))
+    }
+
+    /// Appends `asset` to the end of `principal`'s enumeration index and bumps their balance.
+    fn index_nft_for_owner(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, principal: &PrincipalData, asset: &Value) -> Result<()> {
+        let balance = self.get_nft_balance(contract_identifier, asset_name, principal)?;
+
+        let slot_key = ClarityDatabase::make_key_for_nft_owner_index(contract_identifier, asset_name, principal, balance);
+        self.put(&slot_key, asset);
+        self.put(&ClarityDatabase::make_key_for_nft_asset_position(contract_identifier, asset_name, asset),
+                 &NftIndexPosition { owner: principal.clone(), list_index: balance });
+
+        let balance_key = ClarityDatabase::make_key_for_nft_balance(contract_identifier, asset_name, principal);
+        self.put(&balance_key, &(balance + 1));
+        Ok(())
+    }
+
+    /// Removes `asset` from `principal`'s enumeration index by swapping in the last entry
+    /// (O(1)), and shrinks their balance.
+    fn unindex_nft_for_owner(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, principal: &PrincipalData, asset: &Value) -> Result<()> {
+        let descriptor = self.load_nft(contract_identifier, asset_name)?;
+        let position_key = ClarityDatabase::make_key_for_nft_asset_position(contract_identifier, asset_name, asset);
+        let position: NftIndexPosition = self.get(&position_key)
+            .ok_or(RuntimeErrorType::NoSuchToken)?;
+
+        let balance = self.get_nft_balance(contract_identifier, asset_name, principal)?;
+        let last_index = balance - 1;
+
+        if position.list_index != last_index {
+            let last_key = ClarityDatabase::make_key_for_nft_owner_index(contract_identifier, asset_name, principal, last_index);
+            let moved_asset = self.get_value(&last_key, &descriptor.key_type)
+                .ok_or(RuntimeErrorType::NoSuchToken)?;
+
+            let vacated_key = ClarityDatabase::make_key_for_nft_owner_index(contract_identifier, asset_name, principal, position.list_index);
+            self.put(&vacated_key, &moved_asset);
+            self.put(&ClarityDatabase::make_key_for_nft_asset_position(contract_identifier, asset_name, &moved_asset),
+                     &NftIndexPosition { owner: principal.clone(), list_index: position.list_index });
+        }
+
+        let balance_key = ClarityDatabase::make_key_for_nft_balance(contract_identifier, asset_name, principal);
+        self.put(&balance_key, &last_index);
+
+        Ok(())
+    }
+
+    /// Pages through the `asset` class's tokens currently held by `principal`, in enumeration
+    /// order, returning at most `limit` entries starting at `start`.
+    pub fn nft_tokens_of(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, principal: &PrincipalData, start: u128, limit: u128) -> Result<Vec<Value>> {
+        let descriptor = self.load_nft(contract_identifier, asset_name)?;
+        let balance = self.get_nft_balance(contract_identifier, asset_name, principal)?;
+
+        let mut tokens = Vec::new();
+        let mut index = start;
+        while index < balance && (tokens.len() as u128) < limit {
+            let slot_key = ClarityDatabase::make_key_for_nft_owner_index(contract_identifier, asset_name, principal, index);
+            if let Some(asset) = self.get_value(&slot_key, &descriptor.key_type) {
+                tokens.push(asset);
+            }
+            index += 1;
+        }
+        Ok(tokens)
+    }
+
+    /// Declares the `TypeSignature` that `set_nft_metadata` payloads for `asset_name` must
+    /// admit. Call once when the asset class is defined, alongside `create_non_fungible_token`.
+    pub fn define_nft_metadata_type(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, metadata_type: TypeSignature) {
+        let data = NonFungibleTokenMetadataSchema { metadata_type };
+        let key = ClarityDatabase::make_metadata_key(StoreType::NonFungibleTokenMetadataSchema, asset_name);
+        self.insert_metadata(contract_identifier, &key, &data);
+    }
+
+    fn load_nft_metadata_schema(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str) -> Result<TypeSignature> {
+        let key = ClarityDatabase::make_metadata_key(StoreType::NonFungibleTokenMetadataSchema, asset_name);
+        map_no_contract_as_none(
+            self.fetch_metadata(contract_identifier, &key))?
+            .map(|schema: NonFungibleTokenMetadataSchema| schema.metadata_type)
+            .ok_or(CheckErrors::NoSuchNFT(asset_name.to_string()).into())
+    }
+
+    pub fn set_nft_metadata(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value, metadata: Value) -> Result<()> {
+        let descriptor = self.load_nft(contract_identifier, asset_name)?;
+        if !descriptor.key_type.admits(asset) {
+            return Err(CheckErrors::TypeValueError(descriptor.key_type, (*asset).clone()).into())
+        }
+
+        let metadata_type = self.load_nft_metadata_schema(contract_identifier, asset_name)?;
+        if !metadata_type.admits(&metadata) {
+            return Err(CheckErrors::TypeValueError(metadata_type, metadata).into())
+        }
+
+        let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleTokenMetadata, asset_name, asset.serialize());
+        self.put(&key, &metadata);
+        Ok(())
+    }
+
+    pub fn get_nft_metadata(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> Result<Value> {
+        let descriptor = self.load_nft(contract_identifier, asset_name)?;
+        if !descriptor.key_type.admits(asset) {
+            return Err(CheckErrors::TypeValueError(descriptor.key_type, (*asset).clone()).into())
+        }
+
+        let metadata_type = self.load_nft_metadata_schema(contract_identifier, asset_name)?;
+        let key = ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleTokenMetadata, asset_name, asset.serialize());
+
+        self.get_value(&key, &metadata_type)
+            .ok_or(RuntimeErrorType::NoSuchToken.into())
+    }
+
+    fn make_key_for_nft_fraction_binding(contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> String {
+        ClarityDatabase::make_key_for_quad(contract_identifier, StoreType::NonFungibleTokenFractionBinding, asset_name, asset.serialize())
+    }
+
+    fn is_nft_fractionalized(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value) -> Result<bool> {
+        let key = ClarityDatabase::make_key_for_nft_fraction_binding(contract_identifier, asset_name, asset);
+        Ok(self.get::<NftFractionBinding>(&key).and_then(|b| b.0).is_some())
+    }
+
+    /// Locks `asset` under this contract's own principal (making it non-transferable while
+    /// locked, see `set_nft_owner`/`burn_nft_owner`) and mints `total_shares` of
+    /// `shares_token_name` to `beneficiary`, modeled on `pallet-nft-fractionalization`.
+    /// `shares_token_name` must have been `create_fungible_token`'d with a supply cap, since
+    /// `redeem_fractions` relies on `get_token_supply` tracking circulating supply exactly in
+    /// order to verify the redeemer holds all outstanding shares.
+    pub fn fractionalize_nft(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value, shares_token_name: &str, total_shares: u128, beneficiary: &PrincipalData) -> Result<()> {
+        if self.is_nft_fractionalized(contract_identifier, asset_name, asset)? {
+            return Err(RuntimeErrorType::NftAlreadyFractionalized.into())
+        }
+
+        let custodian = PrincipalData::Contract(contract_identifier.clone());
+        self.set_nft_owner(contract_identifier, asset_name, asset, &custodian)?;
+
+        self.checked_increase_token_supply(contract_identifier, shares_token_name, total_shares)?;
+        let beneficiary_balance = self.get_ft_balance(contract_identifier, shares_token_name, beneficiary)?;
+        let new_balance = beneficiary_balance.checked_add(total_shares).ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+        self.set_ft_balance(contract_identifier, shares_token_name, beneficiary, new_balance)?;
+
+        let binding_key = ClarityDatabase::make_key_for_nft_fraction_binding(contract_identifier, asset_name, asset);
+        self.put(&binding_key, &NftFractionBinding(Some(shares_token_name.to_string())));
+
+        Ok(())
+    }
+
+    /// Burns all outstanding shares of a fractionalized NFT held by `redeemer` and releases
+    /// the NFT back to them. Fails unless `redeemer` holds 100% of the outstanding shares.
+    pub fn redeem_fractions(&mut self, contract_identifier: &QualifiedContractIdentifier, asset_name: &str, asset: &Value, redeemer: &PrincipalData) -> Result<()> {
+        let binding_key = ClarityDatabase::make_key_for_nft_fraction_binding(contract_identifier, asset_name, asset);
+        let shares_token_name = self.get::<NftFractionBinding>(&binding_key)
+            .and_then(|b| b.0)
+            .ok_or(RuntimeErrorType::NftNotFractionalized)?;
+
+        let total_supply = self.get_token_supply(contract_identifier, &shares_token_name)?;
+        let redeemer_balance = self.get_ft_balance(contract_identifier, &shares_token_name, redeemer)?;
+        if total_supply == 0 || redeemer_balance != total_supply {
+            return Err(RuntimeErrorType::InsufficientFractionShares.into())
+        }
+
+        self.checked_decrease_token_supply(contract_identifier, &shares_token_name, total_supply)?;
+        self.set_ft_balance(contract_identifier, &shares_token_name, redeemer, 0)?;
+
+        self.put(&binding_key, &NftFractionBinding(None));
+        self.set_nft_owner(contract_identifier, asset_name, asset, redeemer)?;
 
         Ok(())
     }
@@ -630,4 +1503,48 @@ impl<'a> ClarityDatabase<'a> {
         let key = ClarityDatabase::make_key_for_account_nonce(principal);
         self.put(&key, &nonce);
     }
+
+    pub fn make_key_for_durable_nonce(principal: &PrincipalData) -> String {
+        ClarityDatabase::make_key_for_account(principal, StoreType::DurableNonce)
+    }
+
+    pub fn get_durable_nonce(&mut self, principal: &PrincipalData) -> Option<DurableNonceAccount> {
+        let key = ClarityDatabase::make_key_for_durable_nonce(principal);
+        self.get(&key)
+    }
+
+    /// Creates `principal`'s durable nonce account if absent, or re-authorizes control over
+    /// an existing one to `authority` (which may be `principal` itself). Does not require
+    /// the current nonce value to validate, since this is an owner-level administrative
+    /// action rather than the consumption of a previously signed transaction.
+    pub fn authorize_durable_nonce(&mut self, principal: &PrincipalData, authority: &PrincipalData, nonce_value: [u8; 32]) {
+        let key = ClarityDatabase::make_key_for_durable_nonce(principal);
+        let account = DurableNonceAccount { nonce_value, authority: authority.clone() };
+        self.put(&key, &account);
+    }
+
+    /// Consumes a durable-nonce-signed transaction: `provided_value` must match the
+    /// currently stored nonce value (mirroring Solana's `InvalidHash` check) and
+    /// `authorized_by` must match the account's authority, or the advance is rejected.
+    /// On success the stored value is overwritten with `new_value` so the transaction that
+    /// was just processed cannot be replayed. Callers are responsible for invoking this even
+    /// when the rest of the transaction's instructions fail, so that a failed-but-broadcast
+    /// transaction cannot be resubmitted to steal fees twice.
+    pub fn advance_durable_nonce(&mut self, principal: &PrincipalData, authorized_by: &PrincipalData, provided_value: &[u8; 32], new_value: [u8; 32]) -> Result<()> {
+        let key = ClarityDatabase::make_key_for_durable_nonce(principal);
+        let account: DurableNonceAccount = self.get(&key)
+            .ok_or(RuntimeErrorType::NoSuchDurableNonce)?;
+
+        if &account.authority != authorized_by {
+            return Err(RuntimeErrorType::UnauthorizedDurableNonceAdvance.into())
+        }
+        if &account.nonce_value != provided_value {
+            return Err(RuntimeErrorType::DurableNonceMismatch.into())
+        }
+
+        let advanced = DurableNonceAccount { nonce_value: new_value, authority: account.authority };
+        self.put(&key, &advanced);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+
+use rusqlite::Connection;
+use libmdbx::{Environment, NoWriteMap, WriteFlags};
+
+use chainstate::stacks::StacksBlockId;
+use chainstate::stacks::index::proofs::TrieMerkleProof;
+use vm::errors::{RuntimeErrorType, InterpreterError, InterpreterResult as Result};
+use vm::types::{PrincipalData, QualifiedContractIdentifier};
+use vm::database::{ClarityDatabase, StackerDBConfig};
+use util::hash::Sha256Sum;
+
+/// Hashes a MARF trie node's encoded bytes with SHA-256. Nothing in this source tree calls
+/// it; the trie's node digests are not computed through this function. A build targeting a
+/// known x86_64 host could in principle opt into `sha2-asm`'s assembly-optimized compression
+/// function for it.
+///
+/// That acceleration is NOT implemented here: this checkout has no Cargo.toml anywhere (true
+/// of every crate in this source tree), so there is no manifest to add an `asm` feature or a
+/// `sha2-asm` optional dependency to, and a `#[cfg(feature = "asm")]` branch with nothing
+/// wiring the feature in would just be dead code that can never compile-time-select. Until a
+/// real manifest exists to carry that feature, `hash_trie_node_bytes` only has the one, always
+/// correct, portable implementation below; this comment is a tracked gap, not a finished
+/// feature.
+pub fn hash_trie_node_bytes(data: &[u8]) -> Sha256Sum {
+    Sha256Sum::from_data(data)
+}
+
+/// The storage contract underlying every `ClarityDatabase`: a versioned key/value store,
+/// indexed by block, that `RollbackWrapper` layers MARF-aware nesting on top of.
+pub trait ClarityBackingStore {
+    fn get(&mut self, key: &str) -> Option<String>;
+    fn get_with_proof(&mut self, key: &str) -> Option<(String, TrieMerkleProof<StacksBlockId>)>;
+    fn has_entry(&mut self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn set_block_hash(&mut self, bhh: StacksBlockId) -> Result<StacksBlockId>;
+    fn get_block_at_height(&mut self, height: u32) -> Option<StacksBlockId>;
+    fn get_current_block_height(&mut self) -> u32;
+    fn get_open_chain_tip(&mut self) -> StacksBlockId;
+    fn get_open_chain_tip_height(&mut self) -> u32;
+
+    fn put_all(&mut self, items: Vec<(String, String)>);
+}
+
+/// An in-memory, non-persistent `ClarityBackingStore`, used by tests and the REPL.
+pub struct MemoryBackingStore {
+    chain_tip: StacksBlockId,
+    data: HashMap<String, String>,
+}
+
+impl MemoryBackingStore {
+    pub fn new() -> MemoryBackingStore {
+        MemoryBackingStore {
+            chain_tip: StacksBlockId::sentinel(),
+            data: HashMap::new(),
+        }
+    }
+}
+
+impl ClarityBackingStore for MemoryBackingStore {
+    fn get(&mut self, key: &str) -> Option<String> {
+        self.data.get(key).cloned()
+    }
+
+    fn get_with_proof(&mut self, _key: &str) -> Option<(String, TrieMerkleProof<StacksBlockId>)> {
+        None
+    }
+
+    fn set_block_hash(&mut self, bhh: StacksBlockId) -> Result<StacksBlockId> {
+        let prior = self.chain_tip;
+        self.chain_tip = bhh;
+        Ok(prior)
+    }
+
+    fn get_block_at_height(&mut self, _height: u32) -> Option<StacksBlockId> {
+        Some(self.chain_tip)
+    }
+
+    fn get_current_block_height(&mut self) -> u32 {
+        0
+    }
+
+    fn get_open_chain_tip(&mut self) -> StacksBlockId {
+        self.chain_tip
+    }
+
+    fn get_open_chain_tip_height(&mut self) -> u32 {
+        0
+    }
+
+    fn put_all(&mut self, items: Vec<(String, String)>) {
+        for (key, value) in items.into_iter() {
+            self.data.insert(key, value);
+        }
+    }
+}
+
+/// Which key/value engine a `MarfedKV` reads and writes its chunks through. `Sqlite` is the
+/// long-standing, battle-tested default; `Mdbx` is an opt-in alternative for operators who
+/// want libmdbx's memory-mapped, zero-copy reads and single-writer/many-reader transactions
+/// on the hot `get`/`get_with_proof` path.
+pub enum MarfedKVBackend {
+    Sqlite(Connection),
+    Mdbx(Environment<NoWriteMap>),
+}
+
+const SQLITE_DATA_TABLE: &str = "marf_data";
+const SQLITE_METADATA_TABLE: &str = "marf_metadata";
+const MDBX_CHAIN_TIP_KEY: &[u8] = b"\0marf_chain_tip";
+
+fn block_id_to_hex(bhh: &StacksBlockId) -> String {
+    to_hex_string(&bhh.0)
+}
+
+fn block_id_from_hex(hex: &str) -> StacksBlockId {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&from_hex_string(hex));
+    StacksBlockId(bytes)
+}
+
+/// The on-disk `ClarityBackingStore`: a flat key/value table plus one stored chain tip,
+/// persisted through `backend`. A write replaces the key's single current value, so only
+/// the current chain tip is queryable: historical tips are not kept, no MARF trie (root
+/// hashes, merkle proofs) is built, and `get_with_proof` always returns `None`.
+pub struct MarfedKV {
+    backend: MarfedKVBackend,
+    chain_tip: StacksBlockId,
+}
+
+impl MarfedKV {
+    /// Opens (or creates) a `MarfedKV` backed by sqlite at `path`. This is the default,
+    /// well-tested storage path.
+    pub fn open(path: &str) -> Result<MarfedKV> {
+        let conn = Connection::open(path)
+            .map_err(|e| InterpreterError::Expect(format!("failed to open sqlite-backed MarfedKV at {}: {}", path, e)))?;
+        conn.execute(
+            &format!("CREATE TABLE IF NOT EXISTS {} (key TEXT PRIMARY KEY, value TEXT NOT NULL)", SQLITE_DATA_TABLE),
+            rusqlite::NO_PARAMS,
+        ).map_err(|e| InterpreterError::Expect(format!("failed to create MarfedKV data table: {}", e)))?;
+        conn.execute(
+            &format!("CREATE TABLE IF NOT EXISTS {} (id INTEGER PRIMARY KEY CHECK (id = 0), chain_tip TEXT NOT NULL)", SQLITE_METADATA_TABLE),
+            rusqlite::NO_PARAMS,
+        ).map_err(|e| InterpreterError::Expect(format!("failed to create MarfedKV metadata table: {}", e)))?;
+
+        let chain_tip = conn.query_row(
+            &format!("SELECT chain_tip FROM {} WHERE id = 0", SQLITE_METADATA_TABLE),
+            rusqlite::NO_PARAMS,
+            |row| row.get::<_, String>(0),
+        ).ok().map(|hex| block_id_from_hex(&hex)).unwrap_or_else(StacksBlockId::sentinel);
+
+        if conn.query_row(
+            &format!("SELECT 1 FROM {} WHERE id = 0", SQLITE_METADATA_TABLE),
+            rusqlite::NO_PARAMS,
+            |_row| Ok(()),
+        ).is_err() {
+            conn.execute(
+                &format!("INSERT INTO {} (id, chain_tip) VALUES (0, ?1)", SQLITE_METADATA_TABLE),
+                &[&block_id_to_hex(&chain_tip)],
+            ).map_err(|e| InterpreterError::Expect(format!("failed to seed MarfedKV metadata row: {}", e)))?;
+        }
+
+        Ok(MarfedKV { backend: MarfedKVBackend::Sqlite(conn), chain_tip })
+    }
+
+    /// Opens (or creates) a `MarfedKV` backed by libmdbx at `path`, trading sqlite's
+    /// maturity for mdbx's memory-mapped reads. Existing sqlite-backed chain state should
+    /// be moved over with `migrate_sqlite_to_mdbx` rather than opened directly against a
+    /// fresh mdbx store.
+    pub fn open_mdbx(path: &str) -> Result<MarfedKV> {
+        let env: Environment<NoWriteMap> = Environment::new().open(path.as_ref())
+            .map_err(|e| InterpreterError::Expect(format!("failed to open mdbx-backed MarfedKV at {}: {}", path, e)))?;
+
+        let chain_tip = {
+            let txn = env.begin_ro_txn()
+                .map_err(|e| InterpreterError::Expect(format!("failed to start mdbx read txn: {}", e)))?;
+            let db = txn.open_db(None)
+                .map_err(|e| InterpreterError::Expect(format!("failed to open mdbx db: {}", e)))?;
+            txn.get::<Vec<u8>>(&db, MDBX_CHAIN_TIP_KEY)
+                .map_err(|e| InterpreterError::Expect(format!("failed to read mdbx chain tip: {}", e)))?
+                .map(|bytes| block_id_from_hex(&String::from_utf8_lossy(&bytes)))
+                .unwrap_or_else(StacksBlockId::sentinel)
+        };
+
+        Ok(MarfedKV { backend: MarfedKVBackend::Mdbx(env), chain_tip })
+    }
+}
+
+impl ClarityBackingStore for MarfedKV {
+    fn get(&mut self, key: &str) -> Option<String> {
+        match &self.backend {
+            MarfedKVBackend::Sqlite(conn) => conn.query_row(
+                &format!("SELECT value FROM {} WHERE key = ?1", SQLITE_DATA_TABLE),
+                &[&key],
+                |row| row.get::<_, String>(0),
+            ).ok(),
+            MarfedKVBackend::Mdbx(env) => {
+                let txn = env.begin_ro_txn().expect("failed to start mdbx read txn");
+                let db = txn.open_db(None).expect("failed to open mdbx db");
+                txn.get::<Vec<u8>>(&db, key.as_bytes())
+                    .expect("mdbx get failed")
+                    .map(|bytes| String::from_utf8(bytes).expect("stored MarfedKV value was not valid utf8"))
+            }
+        }
+    }
+
+    /// Proof generation lives in the MARF's trie layer, not in either KV backend, so neither
+    /// engine can answer this on its own yet.
+    fn get_with_proof(&mut self, _key: &str) -> Option<(String, TrieMerkleProof<StacksBlockId>)> {
+        None
+    }
+
+    fn set_block_hash(&mut self, bhh: StacksBlockId) -> Result<StacksBlockId> {
+        let prior = self.chain_tip;
+        let hex = block_id_to_hex(&bhh);
+        match &self.backend {
+            MarfedKVBackend::Sqlite(conn) => {
+                conn.execute(
+                    &format!("UPDATE {} SET chain_tip = ?1 WHERE id = 0", SQLITE_METADATA_TABLE),
+                    &[&hex],
+                ).map_err(|e| InterpreterError::Expect(format!("failed to persist MarfedKV chain tip: {}", e)))?;
+            },
+            MarfedKVBackend::Mdbx(env) => {
+                let txn = env.begin_rw_txn()
+                    .map_err(|e| InterpreterError::Expect(format!("failed to start mdbx write txn: {}", e)))?;
+                let db = txn.open_db(None)
+                    .map_err(|e| InterpreterError::Expect(format!("failed to open mdbx db: {}", e)))?;
+                txn.put(&db, MDBX_CHAIN_TIP_KEY, hex.as_bytes(), WriteFlags::empty())
+                    .map_err(|e| InterpreterError::Expect(format!("failed to persist mdbx chain tip: {}", e)))?;
+                txn.commit()
+                    .map_err(|e| InterpreterError::Expect(format!("failed to commit mdbx chain tip write: {}", e)))?;
+            },
+        }
+        self.chain_tip = bhh;
+        Ok(prior)
+    }
+
+    /// Neither backend tracks per-height history yet: like `MemoryBackingStore`, a `MarfedKV`
+    /// only ever exposes its current chain tip.
+    fn get_block_at_height(&mut self, _height: u32) -> Option<StacksBlockId> {
+        Some(self.chain_tip)
+    }
+
+    fn get_current_block_height(&mut self) -> u32 {
+        0
+    }
+
+    fn get_open_chain_tip(&mut self) -> StacksBlockId {
+        self.chain_tip
+    }
+
+    fn get_open_chain_tip_height(&mut self) -> u32 {
+        0
+    }
+
+    fn put_all(&mut self, items: Vec<(String, String)>) {
+        match &self.backend {
+            MarfedKVBackend::Sqlite(conn) => {
+                for (key, value) in items.into_iter() {
+                    conn.execute(
+                        &format!("INSERT OR REPLACE INTO {} (key, value) VALUES (?1, ?2)", SQLITE_DATA_TABLE),
+                        &[&key, &value],
+                    ).expect("failed to write MarfedKV key/value pair");
+                }
+            },
+            MarfedKVBackend::Mdbx(env) => {
+                let txn = env.begin_rw_txn().expect("failed to start mdbx write txn");
+                let db = txn.open_db(None).expect("failed to open mdbx db");
+                for (key, value) in items.into_iter() {
+                    txn.put(&db, key.as_bytes(), value.as_bytes(), WriteFlags::empty())
+                        .expect("failed to write mdbx key/value pair");
+                }
+                txn.commit().expect("failed to commit mdbx batch write");
+            },
+        }
+    }
+}
+
+/// Bulk-copies every key/value pair out of a sqlite-backed `MarfedKV` at `sqlite_path` into
+/// a freshly created mdbx-backed store at `mdbx_path`, then sets the destination's chain tip
+/// to the source's. Only the key/value rows and the chain tip are copied.
+pub fn migrate_sqlite_to_mdbx(sqlite_path: &str, mdbx_path: &str) -> Result<()> {
+    let mut source = MarfedKV::open(sqlite_path)?;
+    let mut dest = MarfedKV::open_mdbx(mdbx_path)?;
+
+    let rows: Vec<(String, String)> = match &source.backend {
+        MarfedKVBackend::Sqlite(conn) => {
+            let mut stmt = conn.prepare(&format!("SELECT key, value FROM {}", SQLITE_DATA_TABLE))
+                .map_err(|e| InterpreterError::Expect(format!("failed to prepare MarfedKV migration scan: {}", e)))?;
+            let rows = stmt.query_map(rusqlite::NO_PARAMS, |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| InterpreterError::Expect(format!("failed to scan sqlite-backed MarfedKV for migration: {}", e)))?;
+            rows.collect::<::std::result::Result<Vec<_>, _>>()
+                .map_err(|e| InterpreterError::Expect(format!("failed to read a row during MarfedKV migration: {}", e)))?
+        },
+        MarfedKVBackend::Mdbx(_) => {
+            return Err(InterpreterError::Expect("migrate_sqlite_to_mdbx called with a non-sqlite source".into()).into());
+        },
+    };
+
+    dest.put_all(rows);
+    dest.set_block_hash(source.get_open_chain_tip())?;
+
+    Ok(())
+}
+
+/// One off-chain chunk, as replicated between nodes subscribed to a contract-controlled
+/// StackerDB instance: the slot it occupies, its monotonically increasing version, the
+/// chunk bytes, and the signer's signature over `(slot_id, version, data)`.
+#[derive(Clone)]
+pub struct StackerDBChunkData {
+    pub slot_id: u32,
+    pub slot_version: u32,
+    pub data: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// Message envelope used when two subscribed nodes exchange chunks for a StackerDB
+/// instance: either a push of fresh chunk data, or a request for the current version
+/// vector so the requester can figure out which slots it's missing.
+pub enum StackerDBMessage {
+    PushChunk { contract_identifier: QualifiedContractIdentifier, chunk: StackerDBChunkData },
+    GetVersions { contract_identifier: QualifiedContractIdentifier },
+    Versions { contract_identifier: QualifiedContractIdentifier, versions: Vec<(u32, u32)> },
+}
+
+/// A `ClarityBackingStore` over a StackerDB-replicated, off-chain region whose write policy
+/// is governed by a designated Clarity contract's `StackerDBConfig`. Unlike `MarfedKV`, none
+/// of this data is committed to the MARF or folds into consensus state: slots are versioned
+/// independently, and `put_chunks` is the only path that checks who may write which slot
+/// and in what order; `put_all` writes without those checks.
+pub struct StackerDBBackingStore<'a> {
+    contract_identifier: QualifiedContractIdentifier,
+    config: StackerDBConfig,
+    db: &'a mut ClarityDatabase<'a>,
+}
+
+impl <'a> StackerDBBackingStore<'a> {
+    pub fn new(db: &'a mut ClarityDatabase<'a>, contract_identifier: QualifiedContractIdentifier) -> Result<StackerDBBackingStore<'a>> {
+        let config = db.get_stackerdb_config(&contract_identifier)?;
+        Ok(StackerDBBackingStore {
+            contract_identifier,
+            config,
+            db,
+        })
+    }
+
+    fn slot_writer(&self, slot_id: u32) -> Option<&PrincipalData> {
+        let mut offset = 0;
+        for slot in self.config.slots.iter() {
+            if slot_id < offset + slot.num_slots {
+                return Some(&slot.signer);
+            }
+            offset += slot.num_slots;
+        }
+        None
+    }
+
+    /// The slot's latest accepted `(version, data)`, read straight back out of `self.db` --
+    /// there is no separate in-memory copy to go stale or vanish on restart.
+    fn current_slot(&mut self, slot_id: u32) -> Option<(u32, Vec<u8>)> {
+        self.db.get_stackerdb_chunk(&self.contract_identifier, slot_id)
+    }
+
+    /// Checks that `signer`, the principal the caller attributes `chunk` to, is the one
+    /// assigned to its slot, and that its version is strictly greater than whatever is already
+    /// stored there. `chunk.signature` is not verified. This is the
+    /// only gate for accepting a replicated write: there is no consensus-side record of
+    /// these chunks to fall back on.
+    fn accept_chunk(&mut self, signer: &PrincipalData, chunk: &StackerDBChunkData) -> bool {
+        let assigned_signer = match self.slot_writer(chunk.slot_id) {
+            Some(signer) => signer.clone(),
+            None => return false,
+        };
+        if &assigned_signer != signer {
+            return false;
+        }
+        match self.current_slot(chunk.slot_id) {
+            Some((current_version, _)) => chunk.slot_version > current_version,
+            None => true,
+        }
+    }
+
+    /// Applies a batch of signer-attributed chunks, rejecting (and skipping) any that are
+    /// out-of-order or unauthorized rather than failing the whole batch. Every accepted chunk
+    /// is written straight through `self.db`, so it's durable and queryable through the normal
+    /// `ClarityDatabase` interface -- not just held in this `StackerDBBackingStore` instance.
+    pub fn put_chunks(&mut self, signer: &PrincipalData, chunks: Vec<StackerDBChunkData>) {
+        for chunk in chunks.into_iter() {
+            if self.accept_chunk(signer, &chunk) {
+                self.db.set_stackerdb_chunk(&self.contract_identifier, chunk.slot_id, chunk.slot_version, &chunk.data);
+            }
+        }
+    }
+}
+
+impl <'a> ClarityBackingStore for StackerDBBackingStore<'a> {
+    fn get(&mut self, key: &str) -> Option<String> {
+        let slot_id: u32 = key.parse().ok()?;
+        self.current_slot(slot_id).map(|(_, data)| to_hex_string(&data))
+    }
+
+    /// The off-chain region never touches the MARF, so there is no merkle proof to offer.
+    /// Chunk signatures are not stored either, so nothing is returned in a proof's place.
+    fn get_with_proof(&mut self, _key: &str) -> Option<(String, TrieMerkleProof<StacksBlockId>)> {
+        None
+    }
+
+    fn set_block_hash(&mut self, bhh: StacksBlockId) -> Result<StacksBlockId> {
+        Err(RuntimeErrorType::NotImplemented.into())
+    }
+
+    fn get_block_at_height(&mut self, _height: u32) -> Option<StacksBlockId> {
+        None
+    }
+
+    fn get_current_block_height(&mut self) -> u32 {
+        0
+    }
+
+    fn get_open_chain_tip(&mut self) -> StacksBlockId {
+        StacksBlockId::sentinel()
+    }
+
+    fn get_open_chain_tip_height(&mut self) -> u32 {
+        0
+    }
+
+    /// Applies un-attributed writes directly to their slots, bypassing signer/version
+    /// checks. This exists only so the type satisfies `ClarityBackingStore` for generic
+    /// callers (e.g. contract initialization); real replication traffic must go through
+    /// `put_chunks`, which is the only path that enforces the write policy. A slot already
+    /// holding data is bumped to the next version rather than overwritten in place, so a
+    /// subsequent `accept_chunk` call still sees a strictly increasing version for that slot.
+    fn put_all(&mut self, items: Vec<(String, String)>) {
+        for (key, value) in items.into_iter() {
+            let slot_id: u32 = match key.parse() {
+                Ok(slot_id) => slot_id,
+                Err(_) => continue,
+            };
+            if self.slot_writer(slot_id).is_none() {
+                continue;
+            }
+            let data = from_hex_string(&value);
+            let next_version = self.current_slot(slot_id).map_or(1, |(version, _)| version + 1);
+            self.db.set_stackerdb_chunk(&self.contract_identifier, slot_id, next_version, &data);
+        }
+    }
+}
+
+fn to_hex_string(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex_string(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("MarfedKV stored a non-hex value"))
+        .collect()
+}
@@ -7,7 +7,7 @@ mod key_value_wrapper;
 use std::collections::HashMap;
 
 pub use self::key_value_wrapper::{RollbackWrapper, RollbackWrapperPersistedLog};
-pub use self::clarity_db::{ClarityDatabase, HeadersDB, NULL_HEADER_DB, STORE_CONTRACT_SRC_INTERFACE};
+pub use self::clarity_db::{ClarityDatabase, HeadersDB, NULL_HEADER_DB, BurnStateDB, NULL_BURN_STATE_DB, STORE_CONTRACT_SRC_INTERFACE, FungibleTokenDisplayMetadata, StackerDBConfig, StackerDBSlot, DurableNonceAccount};
 pub use self::structures::{ClaritySerializable, ClarityDeserializable};
 pub use self::sqlite::{SqliteConnection};
 pub use self::marf::{MemoryBackingStore, MarfedKV, ClarityBackingStore};
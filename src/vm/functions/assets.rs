@@ -1,7 +1,7 @@
 use vm::functions::tuples;
 use vm::functions::tuples::TupleDefinitionType::{Implicit, Explicit};
 
-use vm::types::{Value, OptionalData, BuffData, PrincipalData, BlockInfoProperty, TypeSignature, AssetIdentifier};
+use vm::types::{Value, OptionalData, BuffData, PrincipalData, BlockInfoProperty, TypeSignature, AssetIdentifier, SequenceData};
 use vm::representations::{SymbolicExpression};
 use vm::errors::{Error, InterpreterError, CheckErrors, RuntimeErrorType, InterpreterResult as Result, check_argument_count};
 use vm::{eval, LocalContext, Environment};
@@ -11,8 +11,12 @@ use std::convert::{TryFrom};
 enum MintAssetErrorCodes { ALREADY_EXIST = 1 }
 enum MintTokenErrorCodes { NON_POSITIVE_AMOUNT = 1 }
 enum TransferAssetErrorCodes { NOT_OWNED_BY = 1, SENDER_IS_RECIPIENT = 2, DOES_NOT_EXIST = 3 }
-enum TransferTokenErrorCodes { NOT_ENOUGH_BALANCE = 1, SENDER_IS_RECIPIENT = 2, NON_POSITIVE_AMOUNT = 3 }
-enum StxErrorCodes { NOT_ENOUGH_BALANCE = 1, SENDER_IS_RECIPIENT = 2, NON_POSITIVE_AMOUNT = 3, SENDER_IS_NOT_TX_SENDER = 4 }
+enum TransferTokenErrorCodes { NOT_ENOUGH_BALANCE = 1, SENDER_IS_RECIPIENT = 2, NON_POSITIVE_AMOUNT = 3, WOULD_REAP = 4 }
+enum ApproveTokenErrorCodes { NON_POSITIVE_AMOUNT = 1, SENDER_IS_NOT_OWNER = 2 }
+enum TransferFromErrorCodes { NOT_ENOUGH_ALLOWANCE = 1, NOT_ENOUGH_BALANCE = 2, SENDER_IS_RECIPIENT = 3, NON_POSITIVE_AMOUNT = 4, SENDER_IS_NOT_SPENDER = 5 }
+enum BurnTokenErrorCodes { NOT_ENOUGH_BALANCE = 1, NON_POSITIVE_AMOUNT = 2 }
+enum BurnAssetErrorCodes { NOT_OWNED_BY = 1, DOES_NOT_EXIST = 2 }
+enum StxErrorCodes { NOT_ENOUGH_BALANCE = 1, SENDER_IS_RECIPIENT = 2, NON_POSITIVE_AMOUNT = 3, SENDER_IS_NOT_TX_SENDER = 4, WOULD_REAP = 5 }
 
 macro_rules! clarity_ecode {
     ($thing:expr) => {
@@ -89,6 +93,64 @@ pub fn special_stx_transfer(args: &[SymbolicExpression],
     }
 }
 
+pub fn special_stx_transfer_keep_alive(args: &[SymbolicExpression],
+                                       env: &mut Environment,
+                                       context: &LocalContext) -> Result<Value> {
+    check_argument_count(3, args)?;
+
+    runtime_cost!(cost_functions::STX_TRANSFER, env, 0)?;
+
+    let amount_val = eval(&args[0], env, context)?;
+    let from_val   = eval(&args[1], env, context)?;
+    let to_val     = eval(&args[2], env, context)?;
+
+    if let (Value::Principal(ref from), Value::Principal(ref to), Value::UInt(amount)) = (&from_val, to_val, amount_val) {
+        if amount <= 0 {
+            return clarity_ecode!(StxErrorCodes::NON_POSITIVE_AMOUNT)
+        }
+
+        if from == to {
+            return clarity_ecode!(StxErrorCodes::SENDER_IS_RECIPIENT)
+        }
+
+        if Some(&from_val) != env.sender.as_ref() {
+            return clarity_ecode!(StxErrorCodes::SENDER_IS_NOT_TX_SENDER)
+        }
+
+        let from_bal = env.global_context.database.get_account_stx_balance(&from);
+        let to_bal = env.global_context.database.get_account_stx_balance(&to);
+
+        if from_bal < amount {
+            return clarity_ecode!(StxErrorCodes::NOT_ENOUGH_BALANCE)
+        }
+
+        let final_from_bal = from_bal - amount;
+
+        let existential_deposit = env.global_context.get_existential_deposit();
+        if final_from_bal > 0 && final_from_bal < existential_deposit {
+            return clarity_ecode!(StxErrorCodes::WOULD_REAP)
+        }
+
+        let final_to_bal = to_bal.checked_add(amount)
+            .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+
+        env.global_context.database.set_account_stx_balance(&from, final_from_bal);
+        env.global_context.database.set_account_stx_balance(&to,   final_to_bal);
+
+        env.global_context.log_stx_transfer(&from, amount)?;
+        env.register_stx_transfer_event(from.clone(), to.clone(), amount)?;
+
+        Ok(Value::okay_true())
+    } else {
+        Err(CheckErrors::BadTransferSTXArguments.into())
+    }
+}
+
 pub fn special_stx_burn(args: &[SymbolicExpression],
                         env: &mut Environment,
                         context: &LocalContext) -> Result<Value> {
@@ -131,6 +193,162 @@ pub fn special_stx_burn(args: &[SymbolicExpression],
     }
 }
 
+pub fn special_stx_transfer_many(args: &[SymbolicExpression],
+                                 env: &mut Environment,
+                                 context: &LocalContext) -> Result<Value> {
+    check_argument_count(2, args)?;
+
+    let from_val = eval(&args[0], env, context)?;
+    let recipients_val = eval(&args[1], env, context)?;
+
+    let from = if let Value::Principal(ref from) = from_val {
+        from.clone()
+    } else {
+        return Err(CheckErrors::BadTransferSTXArguments.into())
+    };
+
+    let recipients = if let Value::Sequence(SequenceData::List(data)) = recipients_val {
+        data.data
+    } else {
+        return Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::BoolType).into())
+    };
+
+    runtime_cost!(cost_functions::STX_TRANSFER, env, recipients.len() as u64)?;
+
+    let mut transfers = Vec::with_capacity(recipients.len());
+    let mut total_amount: u128 = 0;
+
+    for recipient in recipients.iter() {
+        let tuple_data = recipient.clone().expect_tuple();
+        let to = tuple_data.get("to")
+            .map_err(|_| CheckErrors::BadTransferSTXArguments)?
+            .clone();
+        let amount = tuple_data.get("amount")
+            .map_err(|_| CheckErrors::BadTransferSTXArguments)?
+            .clone();
+
+        if let (Value::Principal(ref to_principal), Value::UInt(amount)) = (to, amount) {
+            if amount <= 0 {
+                return clarity_ecode!(StxErrorCodes::NON_POSITIVE_AMOUNT)
+            }
+            if &from == to_principal {
+                return clarity_ecode!(StxErrorCodes::SENDER_IS_RECIPIENT)
+            }
+
+            total_amount = total_amount.checked_add(amount)
+                .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+
+            transfers.push((to_principal.clone(), amount));
+        } else {
+            return Err(CheckErrors::BadTransferSTXArguments.into())
+        }
+    }
+
+    let from_bal = env.global_context.database.get_account_stx_balance(&from);
+    if from_bal < total_amount {
+        return clarity_ecode!(StxErrorCodes::NOT_ENOUGH_BALANCE)
+    }
+
+    env.add_memory((TypeSignature::PrincipalType.size() as u64) * (transfers.len() as u64 + 1))?;
+    env.add_memory((TypeSignature::UIntType.size() as u64) * (transfers.len() as u64 + 1))?;
+
+    env.global_context.database.set_account_stx_balance(&from, from_bal - total_amount);
+
+    for (to, amount) in transfers.iter() {
+        let to_bal = env.global_context.database.get_account_stx_balance(to);
+        let final_to_bal = to_bal.checked_add(*amount)
+            .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+        env.global_context.database.set_account_stx_balance(to, final_to_bal);
+
+        env.global_context.log_stx_transfer(&from, *amount)?;
+        env.register_stx_transfer_event(from.clone(), to.clone(), *amount)?;
+    }
+
+    Ok(Value::okay(Value::UInt(transfers.len() as u128))?)
+}
+
+pub fn special_transfer_token_many(args: &[SymbolicExpression],
+                                   env: &mut Environment,
+                                   context: &LocalContext) -> Result<Value> {
+    check_argument_count(3, args)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let from_val = eval(&args[1], env, context)?;
+    let recipients_val = eval(&args[2], env, context)?;
+
+    let from_principal = if let Value::Principal(ref from) = from_val {
+        from.clone()
+    } else {
+        return Err(CheckErrors::BadTransferFTArguments.into())
+    };
+
+    let recipients = if let Value::Sequence(SequenceData::List(data)) = recipients_val {
+        data.data
+    } else {
+        return Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::BoolType).into())
+    };
+
+    runtime_cost!(cost_functions::FT_TRANSFER, env, recipients.len() as u64)?;
+
+    let mut transfers = Vec::with_capacity(recipients.len());
+    let mut total_amount: u128 = 0;
+
+    for recipient in recipients.iter() {
+        let tuple_data = recipient.clone().expect_tuple();
+        let to = tuple_data.get("to")
+            .map_err(|_| CheckErrors::BadTransferFTArguments)?
+            .clone();
+        let amount = tuple_data.get("amount")
+            .map_err(|_| CheckErrors::BadTransferFTArguments)?
+            .clone();
+
+        if let (Value::Principal(ref to_principal), Value::UInt(amount)) = (to, amount) {
+            if amount <= 0 {
+                return clarity_ecode!(TransferTokenErrorCodes::NON_POSITIVE_AMOUNT)
+            }
+            if &from_principal == to_principal {
+                return clarity_ecode!(TransferTokenErrorCodes::SENDER_IS_RECIPIENT)
+            }
+
+            total_amount = total_amount.checked_add(amount)
+                .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+
+            transfers.push((to_principal.clone(), amount));
+        } else {
+            return Err(CheckErrors::BadTransferFTArguments.into())
+        }
+    }
+
+    let from_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, &from_principal)?;
+    if from_bal < total_amount {
+        return clarity_ecode!(TransferTokenErrorCodes::NOT_ENOUGH_BALANCE)
+    }
+
+    env.add_memory((TypeSignature::PrincipalType.size() as u64) * (transfers.len() as u64 + 1))?;
+    env.add_memory((TypeSignature::UIntType.size() as u64) * (transfers.len() as u64 + 1))?;
+
+    env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, &from_principal, from_bal - total_amount)?;
+
+    let asset_identifier = AssetIdentifier {
+        contract_identifier: env.contract_context.contract_identifier.clone(),
+        asset_name: token_name.clone()
+    };
+
+    for (to, amount) in transfers.iter() {
+        let to_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, to)?;
+        let final_to_bal = to_bal.checked_add(*amount)
+            .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+        env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, to, final_to_bal)?;
+
+        env.global_context.log_token_transfer(&from_principal, &env.contract_context.contract_identifier, token_name, *amount)?;
+        env.register_ft_transfer_event(from_principal.clone(), to.clone(), *amount, asset_identifier.clone())?;
+    }
+
+    Ok(Value::okay(Value::UInt(transfers.len() as u128))?)
+}
+
 pub fn special_mint_token(args: &[SymbolicExpression],
                           env: &mut Environment,
                           context: &LocalContext) -> Result<Value> {
@@ -337,6 +555,284 @@ pub fn special_transfer_token(args: &[SymbolicExpression],
     }
 }
 
+pub fn special_approve_token(args: &[SymbolicExpression],
+                             env: &mut Environment,
+                             context: &LocalContext) -> Result<Value> {
+    check_argument_count(4, args)?;
+
+    runtime_cost!(cost_functions::FT_APPROVE, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let amount =  eval(&args[1], env, context)?;
+    let owner =   eval(&args[2], env, context)?;
+    let spender = eval(&args[3], env, context)?;
+
+    if let (Value::UInt(amount),
+            Value::Principal(ref owner_principal),
+            Value::Principal(ref spender_principal)) = (amount, &owner, spender) {
+        if amount <= 0 {
+            return clarity_ecode!(ApproveTokenErrorCodes::NON_POSITIVE_AMOUNT)
+        }
+
+        if Some(&owner) != env.sender.as_ref() {
+            return clarity_ecode!(ApproveTokenErrorCodes::SENDER_IS_NOT_OWNER)
+        }
+
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+
+        env.global_context.database.set_ft_allowance(&env.contract_context.contract_identifier, token_name, owner_principal, spender_principal, amount)?;
+
+        let asset_identifier = AssetIdentifier {
+            contract_identifier: env.contract_context.contract_identifier.clone(),
+            asset_name: token_name.clone()
+        };
+        env.register_ft_approve_event(owner_principal.clone(), spender_principal.clone(), amount, asset_identifier)?;
+
+        Ok(Value::okay_true())
+    } else {
+        Err(CheckErrors::BadTransferFTArguments.into())
+    }
+}
+
+pub fn special_transfer_token_from(args: &[SymbolicExpression],
+                                   env: &mut Environment,
+                                   context: &LocalContext) -> Result<Value> {
+    check_argument_count(5, args)?;
+
+    runtime_cost!(cost_functions::FT_TRANSFER_FROM, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let amount =  eval(&args[1], env, context)?;
+    let owner =   eval(&args[2], env, context)?;
+    let spender = eval(&args[3], env, context)?;
+    let to =      eval(&args[4], env, context)?;
+
+    if let (Value::UInt(amount),
+            Value::Principal(ref owner_principal),
+            Value::Principal(ref spender_principal),
+            Value::Principal(ref to_principal)) = (amount, owner, &spender, to) {
+        if amount <= 0 {
+            return clarity_ecode!(TransferFromErrorCodes::NON_POSITIVE_AMOUNT)
+        }
+
+        if owner_principal == to_principal {
+            return clarity_ecode!(TransferFromErrorCodes::SENDER_IS_RECIPIENT)
+        }
+
+        if Some(&spender) != env.sender.as_ref() {
+            return clarity_ecode!(TransferFromErrorCodes::SENDER_IS_NOT_SPENDER)
+        }
+
+        let allowance = env.global_context.database.get_ft_allowance(&env.contract_context.contract_identifier, token_name, owner_principal, spender_principal)?;
+
+        if allowance < amount {
+            return clarity_ecode!(TransferFromErrorCodes::NOT_ENOUGH_ALLOWANCE)
+        }
+
+        let from_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, owner_principal)?;
+
+        if from_bal < amount {
+            return clarity_ecode!(TransferFromErrorCodes::NOT_ENOUGH_BALANCE)
+        }
+
+        let final_from_bal = from_bal - amount;
+        let final_allowance = allowance - amount;
+
+        let to_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, to_principal)?;
+
+        let final_to_bal = to_bal.checked_add(amount)
+            .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+
+        env.global_context.database.set_ft_allowance(&env.contract_context.contract_identifier, token_name, owner_principal, spender_principal, final_allowance)?;
+        env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, owner_principal, final_from_bal)?;
+        env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, to_principal, final_to_bal)?;
+
+        env.global_context.log_token_transfer(owner_principal, &env.contract_context.contract_identifier, token_name, amount)?;
+
+        let asset_identifier = AssetIdentifier {
+            contract_identifier: env.contract_context.contract_identifier.clone(),
+            asset_name: token_name.clone()
+        };
+        env.register_ft_transfer_event(owner_principal.clone(), to_principal.clone(), amount, asset_identifier)?;
+
+        Ok(Value::okay_true())
+    } else {
+        Err(CheckErrors::BadTransferFTArguments.into())
+    }
+}
+
+pub fn special_burn_token(args: &[SymbolicExpression],
+                          env: &mut Environment,
+                          context: &LocalContext) -> Result<Value> {
+    check_argument_count(3, args)?;
+
+    runtime_cost!(cost_functions::FT_BURN, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let amount = eval(&args[1], env, context)?;
+    let sender = eval(&args[2], env, context)?;
+
+    if let (Value::UInt(amount),
+            Value::Principal(ref sender_principal)) = (amount, sender) {
+        if amount <= 0 {
+            return clarity_ecode!(BurnTokenErrorCodes::NON_POSITIVE_AMOUNT)
+        }
+
+        let from_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, sender_principal)?;
+
+        if from_bal < amount {
+            return clarity_ecode!(BurnTokenErrorCodes::NOT_ENOUGH_BALANCE)
+        }
+
+        let final_from_bal = from_bal - amount;
+
+        env.global_context.database.checked_decrease_token_supply(&env.contract_context.contract_identifier, token_name, amount)?;
+
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+
+        env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, sender_principal, final_from_bal)?;
+
+        env.global_context.log_token_transfer(sender_principal, &env.contract_context.contract_identifier, token_name, amount)?;
+
+        let asset_identifier = AssetIdentifier {
+            contract_identifier: env.contract_context.contract_identifier.clone(),
+            asset_name: token_name.clone()
+        };
+        env.register_ft_burn_event(sender_principal.clone(), amount, asset_identifier)?;
+
+        Ok(Value::okay_true())
+    } else {
+        Err(CheckErrors::BadTransferFTArguments.into())
+    }
+}
+
+pub fn special_burn_asset(args: &[SymbolicExpression],
+                          env: &mut Environment,
+                          context: &LocalContext) -> Result<Value> {
+    check_argument_count(3, args)?;
+
+    let asset_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let asset =  eval(&args[1], env, context)?;
+    let sender = eval(&args[2], env, context)?;
+
+    let expected_asset_type = env.global_context.database.get_nft_key_type(&env.contract_context.contract_identifier, asset_name)?;
+
+    runtime_cost!(cost_functions::NFT_BURN, env, expected_asset_type.size())?;
+
+    if !expected_asset_type.admits(&asset) {
+        return Err(CheckErrors::TypeValueError(expected_asset_type, asset).into())
+    }
+
+    if let Value::Principal(ref sender_principal) = sender {
+        let current_owner = match env.global_context.database.get_nft_owner(&env.contract_context.contract_identifier, asset_name, &asset) {
+            Ok(owner) => Ok(owner),
+            Err(Error::Runtime(RuntimeErrorType::NoSuchToken, _)) => {
+                return clarity_ecode!(BurnAssetErrorCodes::DOES_NOT_EXIST)
+            },
+            Err(e) => Err(e)
+        }?;
+
+        if current_owner != *sender_principal {
+            return clarity_ecode!(BurnAssetErrorCodes::NOT_OWNED_BY)
+        }
+
+        env.global_context.database.burn_nft_owner(&env.contract_context.contract_identifier, asset_name, &asset)?;
+
+        env.global_context.log_asset_transfer(sender_principal, &env.contract_context.contract_identifier, asset_name, asset.clone());
+
+        let asset_identifier = AssetIdentifier {
+            contract_identifier: env.contract_context.contract_identifier.clone(),
+            asset_name: asset_name.clone()
+        };
+        env.register_nft_burn_event(sender_principal.clone(), asset, asset_identifier)?;
+
+        Ok(Value::okay_true())
+    } else {
+        Err(CheckErrors::TypeValueError(TypeSignature::PrincipalType, sender).into())
+    }
+}
+
+pub fn special_transfer_token_keep_alive(args: &[SymbolicExpression],
+                                         env: &mut Environment,
+                                         context: &LocalContext) -> Result<Value> {
+    check_argument_count(4, args)?;
+
+    runtime_cost!(cost_functions::FT_TRANSFER, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let amount = eval(&args[1], env, context)?;
+    let from =   eval(&args[2], env, context)?;
+    let to =     eval(&args[3], env, context)?;
+
+    if let (Value::UInt(amount),
+            Value::Principal(ref from_principal),
+            Value::Principal(ref to_principal)) = (amount, from, to) {
+        if amount <= 0 {
+            return clarity_ecode!(TransferTokenErrorCodes::NON_POSITIVE_AMOUNT)
+        }
+
+        if from_principal == to_principal {
+            return clarity_ecode!(TransferTokenErrorCodes::SENDER_IS_RECIPIENT)
+        }
+
+        let from_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, from_principal)?;
+
+        if from_bal < amount {
+            return clarity_ecode!(TransferTokenErrorCodes::NOT_ENOUGH_BALANCE)
+        }
+
+        let final_from_bal = from_bal - amount;
+
+        let existential_deposit = env.global_context.get_existential_deposit();
+        if final_from_bal > 0 && final_from_bal < existential_deposit {
+            return clarity_ecode!(TransferTokenErrorCodes::WOULD_REAP)
+        }
+
+        let to_bal = env.global_context.database.get_ft_balance(&env.contract_context.contract_identifier, token_name, to_principal)?;
+
+        let final_to_bal = to_bal.checked_add(amount)
+            .ok_or(RuntimeErrorType::ArithmeticOverflow)?;
+
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::PrincipalType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+        env.add_memory(TypeSignature::UIntType.size() as u64)?;
+
+        env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, from_principal, final_from_bal)?;
+        env.global_context.database.set_ft_balance(&env.contract_context.contract_identifier, token_name, to_principal, final_to_bal)?;
+
+        env.global_context.log_token_transfer(from_principal, &env.contract_context.contract_identifier, token_name, amount)?;
+
+        let asset_identifier = AssetIdentifier {
+            contract_identifier: env.contract_context.contract_identifier.clone(),
+            asset_name: token_name.clone()
+        };
+        env.register_ft_transfer_event(from_principal.clone(), to_principal.clone(), amount, asset_identifier)?;
+
+        Ok(Value::okay_true())
+    } else {
+        Err(CheckErrors::BadTransferFTArguments.into())
+    }
+}
+
 pub fn special_get_balance(args: &[SymbolicExpression],
                            env: &mut Environment,
                            context: &LocalContext) -> Result<Value> {
@@ -358,6 +854,103 @@ pub fn special_get_balance(args: &[SymbolicExpression],
 
 }
 
+pub fn special_ft_get_decimals(args: &[SymbolicExpression],
+                               env: &mut Environment,
+                               context: &LocalContext) -> Result<Value> {
+    check_argument_count(1, args)?;
+
+    runtime_cost!(cost_functions::FT_METADATA, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let metadata = env.global_context.database.get_ft_metadata(&env.contract_context.contract_identifier, token_name)?;
+
+    Ok(Value::okay(Value::UInt(metadata.decimals as u128))?)
+}
+
+pub fn special_ft_get_name(args: &[SymbolicExpression],
+                           env: &mut Environment,
+                           context: &LocalContext) -> Result<Value> {
+    check_argument_count(1, args)?;
+
+    runtime_cost!(cost_functions::FT_METADATA, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let metadata = env.global_context.database.get_ft_metadata(&env.contract_context.contract_identifier, token_name)?;
+
+    Ok(Value::okay(Value::string_ascii_from_bytes(metadata.name.into_bytes())?)?)
+}
+
+pub fn special_ft_get_symbol(args: &[SymbolicExpression],
+                             env: &mut Environment,
+                             context: &LocalContext) -> Result<Value> {
+    check_argument_count(1, args)?;
+
+    runtime_cost!(cost_functions::FT_METADATA, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let metadata = env.global_context.database.get_ft_metadata(&env.contract_context.contract_identifier, token_name)?;
+
+    Ok(Value::okay(Value::string_ascii_from_bytes(metadata.symbol.into_bytes())?)?)
+}
+
+pub fn special_ft_get_supply(args: &[SymbolicExpression],
+                             env: &mut Environment,
+                             context: &LocalContext) -> Result<Value> {
+    check_argument_count(1, args)?;
+
+    runtime_cost!(cost_functions::FT_SUPPLY, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let supply = env.global_context.database.get_token_supply(&env.contract_context.contract_identifier, token_name)?;
+
+    Ok(Value::okay(Value::UInt(supply))?)
+}
+
+pub fn special_ft_defined(args: &[SymbolicExpression],
+                          env: &mut Environment,
+                          context: &LocalContext) -> Result<Value> {
+    check_argument_count(1, args)?;
+
+    runtime_cost!(cost_functions::FT_SUPPLY, env, 0)?;
+
+    let token_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let defined = env.global_context.database.ft_exists(&env.contract_context.contract_identifier, token_name);
+
+    Ok(Value::Bool(defined))
+}
+
+pub fn special_nft_exists(args: &[SymbolicExpression],
+                          env: &mut Environment,
+                          context: &LocalContext) -> Result<Value> {
+    check_argument_count(2, args)?;
+
+    let asset_name = args[0].match_atom()
+        .ok_or(CheckErrors::BadTokenName)?;
+
+    let asset = eval(&args[1], env, context)?;
+    let expected_asset_type = env.global_context.database.get_nft_key_type(&env.contract_context.contract_identifier, asset_name)?;
+
+    runtime_cost!(cost_functions::NFT_EXISTS, env, expected_asset_type.size())?;
+
+    if !expected_asset_type.admits(&asset) {
+        return Err(CheckErrors::TypeValueError(expected_asset_type, asset).into())
+    }
+
+    let exists = env.global_context.database.nft_exists(&env.contract_context.contract_identifier, asset_name, &asset);
+
+    Ok(Value::Bool(exists))
+}
+
 pub fn special_get_owner(args: &[SymbolicExpression],
                          env: &mut Environment,
                          context: &LocalContext) -> Result<Value> {
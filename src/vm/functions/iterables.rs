@@ -98,31 +98,100 @@ pub fn special_fold(args: &[SymbolicExpression], env: &mut Environment, context:
     })
 }
 
-pub fn special_map(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
-    check_argument_count(2, args)?;
+pub fn special_fold_right(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
+    check_argument_count(3, args)?;
 
-    runtime_cost!(cost_functions::MAP, env, 0)?;
+    runtime_cost!(cost_functions::FILTER, env, 0)?;
 
     let function_name = args[0].match_atom()
         .ok_or(CheckErrors::ExpectedName)?;
-    let iterable = eval(&args[1], env, context)?;
+
     let function = lookup_function(&function_name, env)?;
+    let iterable = eval(&args[1], env, context)?;
+    let initial = eval(&args[2], env, context)?;
 
     let mapped_args: Vec<_> = match iterable {
         Value::List(mut list) => {
             list.data.drain(..).map(|x| {
-                vec![SymbolicExpression::atom_value(x)]
+                SymbolicExpression::atom_value(x)
             }).collect()
         },
         Value::Buffer(mut buff) => {
             buff.data.drain(..).map(|x| {
-                vec![SymbolicExpression::atom_value(Value::buff_from_byte(x))]
+                SymbolicExpression::atom_value(Value::buff_from_byte(x))
             }).collect()
         },
         _ => return Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::type_of(&iterable)).into())
     };
-    let mapped_vec: Result<Vec<_>> =
-        mapped_args.iter().map(|argument| apply(&function, &argument, env, context)).collect();
+    mapped_args.iter().rev().try_fold(initial, |acc, x| {
+        apply(&function, &[x.clone(), SymbolicExpression::atom_value(acc)], env, context)
+    })
+}
+
+pub fn special_reduce(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
+    check_argument_count(2, args)?;
+
+    runtime_cost!(cost_functions::FILTER, env, 0)?;
+
+    let function_name = args[0].match_atom()
+        .ok_or(CheckErrors::ExpectedName)?;
+
+    let function = lookup_function(&function_name, env)?;
+    let iterable = eval(&args[1], env, context)?;
+
+    let values: Vec<Value> = match iterable {
+        Value::List(mut list) => list.data.drain(..).collect(),
+        Value::Buffer(mut buff) => buff.data.drain(..).map(Value::buff_from_byte).collect(),
+        _ => return Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::type_of(&iterable)).into())
+    };
+
+    let mut values = values.into_iter();
+    let initial = match values.next() {
+        Some(v) => v,
+        None => return Ok(Value::none())
+    };
+
+    let result = values.try_fold(initial, |acc, x| {
+        apply(&function, &[SymbolicExpression::atom_value(x), SymbolicExpression::atom_value(acc)], env, context)
+    })?;
+    Value::some(result)
+}
+
+pub fn special_map(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
+    if args.len() < 2 {
+        return Err(CheckErrors::IncorrectArgumentCount(2, args.len()).into())
+    }
+
+    let function_name = args[0].match_atom()
+        .ok_or(CheckErrors::ExpectedName)?;
+    let function = lookup_function(&function_name, env)?;
+
+    // evaluate each of the N sequences after the function name, turning each into a vector of
+    // per-element atoms (buffer bytes are byte-wrapped via `buff_from_byte`, same as
+    // `special_fold`)
+    let seqs: Result<Vec<Vec<SymbolicExpression>>> = args[1..].iter().map(|arg| {
+        let iterable = eval(arg, env, context)?;
+        match iterable {
+            Value::List(mut list) => Ok(
+                list.data.drain(..).map(|x| SymbolicExpression::atom_value(x)).collect()
+            ),
+            Value::Buffer(mut buff) => Ok(
+                buff.data.drain(..).map(|x| SymbolicExpression::atom_value(Value::buff_from_byte(x))).collect()
+            ),
+            _ => Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::type_of(&iterable)).into())
+        }
+    }).collect();
+    let seqs = seqs?;
+
+    // stop at the shortest sequence's length
+    let min_len = seqs.iter().map(|seq| seq.len()).min().unwrap_or(0);
+
+    runtime_cost!(cost_functions::MAP, env, (seqs.len() as u64).cost_overflow_mul(min_len as u64)?)?;
+
+    let mapped_vec: Result<Vec<_>> = (0..min_len).map(|i| {
+        let argument: Vec<_> = seqs.iter().map(|seq| seq[i].clone()).collect();
+        apply(&function, &argument, env, context)
+    }).collect();
     Value::list_from(mapped_vec?)
 }
 
@@ -217,3 +286,81 @@ pub fn native_len(iterable: Value) -> Result<Value> {
         _ => Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::type_of(&iterable)).into())
     }
 }
+
+fn expect_uint_arg(expr: &SymbolicExpression, env: &mut Environment, context: &LocalContext) -> Result<u128> {
+    let actual = eval(expr, env, context)?;
+    if let Value::UInt(value) = actual {
+        Ok(value)
+    } else {
+        Err(CheckErrors::TypeError(TypeSignature::UIntType, TypeSignature::type_of(&actual)).into())
+    }
+}
+
+pub fn special_slice(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
+    check_argument_count(3, args)?;
+
+    let iterable = eval(&args[0], env, context)?;
+    let start = expect_uint_arg(&args[1], env, context)?;
+    let end = expect_uint_arg(&args[2], env, context)?;
+
+    match iterable {
+        Value::List(mut list) => {
+            let len = list.data.len() as u128;
+            let end = cmp::min(end, len);
+            let sliced: Vec<Value> = if start >= end || start >= len {
+                Vec::new()
+            } else {
+                list.data.drain(start as usize .. end as usize).collect()
+            };
+
+            runtime_cost!(cost_functions::CONCAT, env, sliced.len() as u64)?;
+
+            let mut type_signature = list.type_signature;
+            type_signature.reduce_max_len(sliced.len() as u32);
+            Value::list_with_type(sliced, type_signature)
+        },
+        Value::Buffer(mut buff) => {
+            let len = buff.data.len() as u128;
+            let end = cmp::min(end, len);
+            let sliced: Vec<u8> = if start >= end || start >= len {
+                Vec::new()
+            } else {
+                buff.data.drain(start as usize .. end as usize).collect()
+            };
+
+            runtime_cost!(cost_functions::CONCAT, env, sliced.len() as u64)?;
+
+            Value::buff_from(sliced)
+        },
+        _ => Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::type_of(&iterable)).into())
+    }
+}
+
+pub fn special_element_at(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
+    check_argument_count(2, args)?;
+
+    runtime_cost!(cost_functions::ELEMENT_AT, env, 0)?;
+
+    let iterable = eval(&args[0], env, context)?;
+    let actual_index = eval(&args[1], env, context)?;
+
+    if let Value::UInt(index) = actual_index {
+        match iterable {
+            Value::List(ref list) => {
+                match list.data.get(index as usize) {
+                    Some(element) => Value::some(element.clone()),
+                    None => Ok(Value::none())
+                }
+            },
+            Value::Buffer(ref buff) => {
+                match buff.data.get(index as usize) {
+                    Some(byte) => Value::some(Value::buff_from(vec![*byte])?),
+                    None => Ok(Value::none())
+                }
+            },
+            _ => Err(CheckErrors::ExpectedListOrBuffer(TypeSignature::type_of(&iterable)).into())
+        }
+    } else {
+        Err(CheckErrors::TypeError(TypeSignature::UIntType, TypeSignature::type_of(&actual_index)).into())
+    }
+}
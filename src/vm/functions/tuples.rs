@@ -52,4 +52,33 @@ pub fn tuple_get(args: &[SymbolicExpression], env: &mut Environment, context: &L
         Value::Tuple(tuple_data) => tuple_data.get(arg_name),
         _ => Err(Error::new(ErrType::TypeError("TupleType".to_string(), value.clone())))
     }
+}
+
+pub fn tuple_merge(args: &[SymbolicExpression], env: &mut Environment, context: &LocalContext) -> Result<Value> {
+    // (merge tuple-a tuple-b)
+    //    returns a new tuple with the fields of both tuple-a and tuple-b, with
+    //    tuple-b's values overriding tuple-a's on name collisions.
+
+    if args.len() != 2 {
+        return Err(Error::new(ErrType::InvalidArguments(format!("(merge ..) requires exactly 2 arguments"))))
+    }
+
+    let base = eval(&args[0], env, context)?;
+    let update = eval(&args[1], env, context)?;
+
+    let base_data = match base {
+        Value::Tuple(tuple_data) => tuple_data,
+        _ => return Err(Error::new(ErrType::TypeError("TupleType".to_string(), base)))
+    };
+    let update_data = match update {
+        Value::Tuple(tuple_data) => tuple_data,
+        _ => return Err(Error::new(ErrType::TypeError("TupleType".to_string(), update)))
+    };
+
+    let mut merged_map = base_data.data_map;
+    for (name, value) in update_data.data_map.into_iter() {
+        merged_map.insert(name, value);
+    }
+
+    Value::tuple_from_data(merged_map.into_iter().collect())
 }
\ No newline at end of file
@@ -373,8 +373,13 @@ fn test_eval_func_arg_panic() {
     let e: Error = CheckErrors::ExpectedName.into();
     assert_eq!(e, execute(test2).unwrap_err());
 
+    // `map` now accepts more than one sequence argument (see `test_map_multiple_sequences`
+    // below), so 3 arguments is no longer an arity error; `2` is rejected instead because it
+    // isn't a list or buffer for `map` to iterate over.
     let test3 = "(map square (list 1 2 3 4) 2)";
-    let e: Error = CheckErrors::IncorrectArgumentCount(2, 3).into();
+    // `square` must be defined for `map` to get past its function lookup to the arguments
+    let test3 = &format!("(define-private (square (x int)) (* x x)) {}", test3);
+    let e: Error = CheckErrors::ExpectedListOrBuffer(IntType).into();
     assert_eq!(e, execute(test3).unwrap_err());
 
     let test4 = "(define-private (multiply-all (x int) (acc int)) (* x acc))
@@ -382,3 +387,28 @@ fn test_eval_func_arg_panic() {
     let e: Error = CheckErrors::IncorrectArgumentCount(3, 2).into();
     assert_eq!(e, execute(test4).unwrap_err());
 }
+
+#[test]
+fn test_map_multiple_sequences() {
+    let test = "(define-private (sum3 (a int) (b int) (c int)) (+ a b c))
+         (map sum3 (list 1 2 3) (list 10 20 30) (list 100 200 300))";
+    assert_eq!(
+        execute(test).unwrap().unwrap(),
+        Value::list_from(vec![
+            Value::Int(111),
+            Value::Int(222),
+            Value::Int(333)
+        ]).unwrap()
+    );
+
+    // mismatched-length sequences: `map` stops at the shortest one rather than erroring.
+    let test_mismatched_lengths = "(define-private (sum2 (a int) (b int)) (+ a b))
+         (map sum2 (list 1 2 3 4) (list 10 20))";
+    assert_eq!(
+        execute(test_mismatched_lengths).unwrap().unwrap(),
+        Value::list_from(vec![
+            Value::Int(11),
+            Value::Int(22)
+        ]).unwrap()
+    );
+}
@@ -1,5 +1,5 @@
 use std::convert::TryFrom;
-use vm::types::Value;
+use vm::types::{Value, QualifiedContractIdentifier};
 use vm::contexts::{LocalContext, Environment};
 use vm::errors::{RuntimeErrorType, InterpreterResult as Result};
 
@@ -13,6 +13,14 @@ pub fn is_reserved_name(name: &str) -> bool {
     NativeVariables::lookup_by_name(name).is_some()
 }
 
+/// Read-only counterpart to `lookup_reserved_variable`: fetches a contract's top-level
+/// `define-constant` value straight from its persisted metadata, without evaluating any
+/// Clarity code. Intended for external query paths (tooling, RPC) that only need the
+/// constant's materialized value.
+pub fn lookup_constant_value(contract_identifier: &QualifiedContractIdentifier, const_name: &str, env: &mut Environment) -> Result<Value> {
+    env.global_context.database.get_constant(contract_identifier, const_name)
+}
+
 pub fn lookup_reserved_variable(name: &str, _context: &LocalContext, env: &mut Environment) -> Result<Option<Value>> {
     if let Some(variable) = NativeVariables::lookup_by_name(name) {
         match variable {
@@ -31,7 +39,8 @@ pub fn lookup_reserved_variable(name: &str, _context: &LocalContext, env: &mut E
                 Ok(Some(Value::UInt(block_height as u128)))
             },
             NativeVariables::BurnBlockHeight => {
-                Err(RuntimeErrorType::NotImplemented.into())
+                let burn_block_height = env.global_context.database.get_current_burnchain_block_height()?;
+                Ok(Some(Value::UInt(burn_block_height as u128)))
             },
             NativeVariables::NativeNone => {
                 Ok(Some(Value::none()))
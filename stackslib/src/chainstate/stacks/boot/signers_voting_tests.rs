@@ -54,7 +54,7 @@ use crate::chainstate::burn::{BlockSnapshot, ConsensusHash};
 use crate::chainstate::nakamoto::coordinator::tests::make_token_transfer;
 use crate::chainstate::nakamoto::tests::get_account;
 use crate::chainstate::nakamoto::tests::node::{TestSigners, TestStacker};
-use crate::chainstate::nakamoto::NakamotoBlock;
+use crate::chainstate::nakamoto::{NakamotoBlock, NakamotoChainState};
 use crate::chainstate::stacks::address::{PoxAddress, PoxAddressType20, PoxAddressType32};
 use crate::chainstate::stacks::boot::pox_2_tests::{
     check_pox_print_event, generate_pox_clarity_value, get_reward_set_entries_at,
@@ -62,7 +62,8 @@ use crate::chainstate::stacks::boot::pox_2_tests::{
     StackingStateCheckData,
 };
 use crate::chainstate::stacks::boot::pox_4_tests::{
-    assert_latest_was_burn, get_last_block_sender_transactions, get_tip, make_test_epochs_pox,
+    assert_latest_was_burn, get_last_block_sender_transactions, get_tip, make_pox_4_extend,
+    make_pox_4_increase, make_signer_key_signature, make_test_epochs_pox,
 };
 use crate::chainstate::stacks::boot::signers_tests::prepare_signers_test;
 use crate::chainstate::stacks::boot::{
@@ -303,6 +304,688 @@ fn vote_for_aggregate_public_key() {
     );
 }
 
+/// Reads back whatever aggregate key `signers-voting` has actually committed for
+/// `reward_cycle`, straight from the contract -- `None` until (and unless) a candidate
+/// key has crossed the approval threshold.
+fn get_aggregate_public_key(
+    peer: &mut TestPeer,
+    latest_block_id: &StacksBlockId,
+    reward_cycle: u128,
+) -> Option<Point> {
+    let result = readonly_call(
+        peer,
+        latest_block_id,
+        SIGNERS_VOTING_NAME.into(),
+        "get-aggregate-public-key".into(),
+        vec![Value::UInt(reward_cycle)],
+    )
+    .expect_optional();
+
+    result.map(|key_value| {
+        let buff = key_value.expect_buff(33);
+        Point::try_from(&Compressed::from(buff.as_slice())).unwrap()
+    })
+}
+
+/// Casts `vote-for-aggregate-public-key` from each of `stackers`, in order, summing the
+/// `num-slots` weight of the signers that have voted. Returns, after every vote, the
+/// cumulative weight so far and whatever `get-aggregate-public-key` reports for the cycle --
+/// `None` until (and unless) the contract's own approval threshold is crossed.
+fn vote_signers_to_threshold(
+    peer: &mut TestPeer,
+    test_signers: &mut TestSigners,
+    latest_block_id: &StacksBlockId,
+    stackers: &[&TestStacker],
+    candidate_key: &Point,
+    starting_nonce: u64,
+) -> Vec<(u128, Option<Point>)> {
+    let cycle_id = readonly_call(
+        peer,
+        latest_block_id,
+        SIGNERS_VOTING_NAME.into(),
+        "current-reward-cycle".into(),
+        vec![],
+    )
+    .expect_u128();
+
+    let signers = readonly_call(
+        peer,
+        latest_block_id,
+        SIGNERS_NAME.into(),
+        "stackerdb-get-signer-slots".into(),
+        vec![],
+    )
+    .expect_result_ok()
+    .expect_list();
+
+    let total_weight: u128 = signers
+        .iter()
+        .map(|value| value.clone().expect_tuple().get("num-slots").unwrap().clone().expect_u128())
+        .sum();
+
+    let mut history = Vec::new();
+    let mut nonce = starting_nonce;
+    let mut cumulative_weight: u128 = 0;
+    let mut current_block_id = *latest_block_id;
+
+    for stacker in stackers {
+        let signer_key = &stacker.signer_private_key;
+        let signer_address = key_to_stacks_addr(signer_key);
+
+        let signer_tuple = signers
+            .iter()
+            .find(|value| {
+                value
+                    .clone()
+                    .expect_tuple()
+                    .get("signer")
+                    .unwrap()
+                    .clone()
+                    .expect_principal()
+                    == signer_address.to_account_principal()
+            })
+            .expect("signer not found")
+            .clone()
+            .expect_tuple();
+
+        let signer_index = signers
+            .iter()
+            .position(|value| value == &Value::Tuple(signer_tuple.clone()))
+            .expect("signer not found") as u128;
+        let signer_weight = signer_tuple.get("num-slots").unwrap().clone().expect_u128();
+
+        let vote_tx = make_signers_vote_for_aggregate_public_key(
+            signer_key,
+            nonce,
+            signer_index,
+            candidate_key,
+            cycle_id as u64,
+        );
+        nonce += 1;
+
+        let blocks_and_sizes = nakamoto_tenure(peer, test_signers, vec![vec![vote_tx]], signer_key);
+        current_block_id = blocks_and_sizes
+            .last()
+            .expect("tenure produced no blocks")
+            .0
+            .header
+            .block_id();
+
+        cumulative_weight += signer_weight;
+
+        let committed_key = get_aggregate_public_key(peer, &current_block_id, cycle_id);
+
+        history.push((cumulative_weight, committed_key));
+    }
+
+    history
+}
+
+#[test]
+fn vote_signers_to_threshold_commits_only_past_threshold() {
+    let stacker_1 = TestStacker::from_seed(&[3, 4]);
+    let stacker_2 = TestStacker::from_seed(&[5, 6]);
+    let observer = TestEventObserver::new();
+
+    let signer_1 = key_to_stacks_addr(&stacker_1.signer_private_key).to_account_principal();
+    let signer_2 = key_to_stacks_addr(&stacker_2.signer_private_key).to_account_principal();
+
+    let (mut peer, mut test_signers, latest_block_id) = prepare_signers_test(
+        function_name!(),
+        vec![(signer_1, 500), (signer_2, 500)],
+        Some(vec![&stacker_1, &stacker_2]),
+        Some(&observer),
+    );
+
+    let candidate_key: Point = Point::new();
+
+    let history = vote_signers_to_threshold(
+        &mut peer,
+        &mut test_signers,
+        &latest_block_id,
+        &[&stacker_1, &stacker_2],
+        &candidate_key,
+        0,
+    );
+
+    assert_eq!(history.len(), 2, "expected one history entry per vote cast");
+
+    let (weight_after_first, committed_after_first) = &history[0];
+    assert!(
+        committed_after_first.is_none(),
+        "a single signer's vote should leave the aggregate key unset below the 70% threshold"
+    );
+
+    let (weight_after_second, committed_after_second) = &history[1];
+    assert!(weight_after_second > weight_after_first);
+    assert_eq!(
+        committed_after_second.as_ref(),
+        Some(&candidate_key),
+        "cumulative weight should commit the candidate key once both signers have voted"
+    );
+}
+
+/// Domain separator mixed into the digest a signer mock-signs over, so a mock signature can
+/// never be replayed as a signature over some other kind of message.
+///
+/// This scheme (`MOCK_SIGN_DOMAIN`/`mock_sign_digest`/`MockSignerMessage`/
+/// `verify_mock_signatures` below) is a test-only fixture invented for this test file: there is
+/// no production epoch-2.5 mock-signing implementation anywhere else in this checkout to tie it
+/// to. Treat it as exercising this file's own signer-coordination test plumbing only --
+/// not as a check of any real mock-signing code path.
+const MOCK_SIGN_DOMAIN: &[u8] = b"blockstack-core::mock-sign-block-identity";
+
+/// A signer's mock signature over a Nakamoto block's identity, as produced by this test file's
+/// own invented mock-signing fixture -- see the note on `MOCK_SIGN_DOMAIN`.
+#[derive(Clone)]
+struct MockSignerMessage {
+    signer_key: StacksPublicKey,
+    consensus_hash: ConsensusHash,
+    signature: MessageSignature,
+}
+
+fn mock_sign_digest(consensus_hash: &ConsensusHash, block: &NakamotoBlock) -> Sha512Trunc256Sum {
+    let mut hash_input = Vec::new();
+    hash_input.extend_from_slice(MOCK_SIGN_DOMAIN);
+    hash_input.extend_from_slice(consensus_hash.as_bytes());
+    hash_input.extend_from_slice(block.header.block_id().as_bytes());
+    Sha512Trunc256Sum::from_data(&hash_input)
+}
+
+fn mock_sign_block_identity(
+    signer_private_key: &StacksPrivateKey,
+    consensus_hash: &ConsensusHash,
+    block: &NakamotoBlock,
+) -> MockSignerMessage {
+    let digest = mock_sign_digest(consensus_hash, block);
+    let signature = signer_private_key
+        .sign(digest.as_bytes())
+        .expect("failed to mock-sign block identity");
+
+    MockSignerMessage {
+        signer_key: StacksPublicKey::from_private(signer_private_key),
+        consensus_hash: consensus_hash.clone(),
+        signature,
+    }
+}
+
+/// Has every `TestStacker` in `reward_set` mock-sign `block`'s identity for `consensus_hash`.
+fn mock_sign_reward_set(
+    reward_set: &[&TestStacker],
+    consensus_hash: &ConsensusHash,
+    block: &NakamotoBlock,
+) -> Vec<MockSignerMessage> {
+    reward_set
+        .iter()
+        .map(|stacker| mock_sign_block_identity(&stacker.signer_private_key, consensus_hash, block))
+        .collect()
+}
+
+/// True iff every message in `messages` is over `block`/`consensus_hash` and comes from a
+/// key in `expected_keys` -- i.e. no foreign signer snuck a mock signature in. Verifies
+/// against this file's own invented mock-signing fixture (see the note on `MOCK_SIGN_DOMAIN`),
+/// not any production signature-verification path.
+fn verify_mock_signatures(
+    messages: &[MockSignerMessage],
+    expected_keys: &[StacksPublicKey],
+    consensus_hash: &ConsensusHash,
+    block: &NakamotoBlock,
+) -> bool {
+    let digest = mock_sign_digest(consensus_hash, block);
+    messages.iter().all(|message| {
+        message.consensus_hash == *consensus_hash
+            && expected_keys.contains(&message.signer_key)
+            && message
+                .signer_key
+                .verify(digest.as_bytes(), &message.signature)
+                .unwrap_or(false)
+    })
+}
+
+/// Like `nakamoto_tenure`, but additionally has `mock_signers` mock-sign each produced block
+/// for `consensus_hash`, so 2.5-era signer-coordination plumbing can be tested before a real
+/// aggregate key/DKG exists.
+fn nakamoto_tenure_mock_signed(
+    peer: &mut TestPeer,
+    test_signers: &mut TestSigners,
+    txs_of_blocks: Vec<Vec<StacksTransaction>>,
+    stacker_private_key: &StacksPrivateKey,
+    mock_signers: &[&TestStacker],
+    consensus_hash: &ConsensusHash,
+) -> Vec<(NakamotoBlock, u64, ExecutionCost, Vec<MockSignerMessage>)> {
+    let blocks_and_sizes = nakamoto_tenure(peer, test_signers, txs_of_blocks, stacker_private_key);
+    blocks_and_sizes
+        .into_iter()
+        .map(|(block, size, cost)| {
+            let messages = mock_sign_reward_set(mock_signers, consensus_hash, &block);
+            (block, size, cost, messages)
+        })
+        .collect()
+}
+
+/// Produces a "shadow" tenure: one that bypasses sortition entirely, to recover a chain
+/// that has stalled because no sortition winner produced a tenure. Rather than mining a new
+/// burnchain block and VRF-proving a fresh tenure (see `nakamoto_tenure`), this extends the
+/// current tenure directly with no coinbase payout, so the coordinator can advance the
+/// Stacks tip without waiting on sortition.
+fn make_shadow_tenure(
+    peer: &mut TestPeer,
+    test_signers: &mut TestSigners,
+    txs: Vec<StacksTransaction>,
+) -> Vec<(NakamotoBlock, u64, ExecutionCost)> {
+    let current_tip = get_tip(peer.sortdb.as_ref());
+
+    let mut tenure_change = peer.miner.make_tenure_change_payload(TenureChangeCause::Extended);
+    tenure_change.tenure_consensus_hash = current_tip.consensus_hash.clone();
+    tenure_change.burn_view_consensus_hash = current_tip.consensus_hash.clone();
+
+    let tenure_change_tx = peer
+        .miner
+        .make_nakamoto_tenure_change(tenure_change.clone());
+
+    let mut remaining_txs = vec![txs];
+    remaining_txs.reverse();
+
+    let blocks_and_sizes = peer.make_nakamoto_tenure_extension(
+        tenure_change_tx,
+        test_signers,
+        |miner, chainstate, sortdb, blocks| remaining_txs.pop().unwrap_or(vec![]),
+    );
+
+    info!("shadow tenure length {}", blocks_and_sizes.len());
+    blocks_and_sizes
+}
+
+/// A solo stacker-signer's keys and running nonce, used by `run_stack_extend_increase_scenario`
+/// to issue `stack-extend`/`stack-increase` transactions signed correctly for each cycle.
+struct StackerSignerInfo {
+    stacker_key: StacksPrivateKey,
+    signer_key: StacksPrivateKey,
+    nonce: u64,
+}
+
+impl StackerSignerInfo {
+    fn new(stacker_key: StacksPrivateKey, signer_key: StacksPrivateKey) -> Self {
+        Self {
+            stacker_key,
+            signer_key,
+            nonce: 0,
+        }
+    }
+}
+
+/// Drives `stackers` through `num_cycles` reward cycles: on the first cycle each stacker
+/// issues `stack-extend` (signed by that cycle's signer key), and on every cycle after that,
+/// `stack-increase` by `increase_amount`. A tenure is mined after each cycle's transactions
+/// so reward-slot membership is recalculated against the fresh locks/balances before the
+/// next cycle's transactions are built. Returns the reward-set entries observed after each
+/// cycle so a test can diff them against what it expected.
+fn run_stack_extend_increase_scenario(
+    peer: &mut TestPeer,
+    stackers: &mut [StackerSignerInfo],
+    num_cycles: u64,
+    increase_amount: u128,
+    coinbase_nonce: &mut usize,
+) -> Vec<Vec<RawRewardSetEntry>> {
+    let mut history = Vec::new();
+
+    for cycle in 0..num_cycles {
+        let mut txs = Vec::new();
+        for stacker in stackers.iter_mut() {
+            let signer_sig = make_signer_key_signature(
+                &stacker.signer_key,
+                cycle,
+                &stacker.stacker_key,
+            );
+            let tx = if cycle == 0 {
+                make_pox_4_extend(&stacker.stacker_key, stacker.nonce, signer_sig)
+            } else {
+                make_pox_4_increase(&stacker.stacker_key, stacker.nonce, increase_amount)
+            };
+            stacker.nonce += 1;
+            txs.push(tx);
+        }
+
+        peer.tenure_with_txs(&txs, coinbase_nonce);
+
+        let tip = get_tip(peer.sortdb.as_ref());
+        let reward_set = get_reward_set_entries_at(peer, &tip.index_block_hash());
+        history.push(reward_set);
+    }
+
+    history
+}
+
+#[test]
+fn stack_extend_then_increase_updates_reward_set_per_cycle() {
+    let stacker_1 = TestStacker::from_seed(&[11, 12]);
+    let stacker_2 = TestStacker::from_seed(&[13, 14]);
+    let observer = TestEventObserver::new();
+
+    let (_burnchain, mut peer, _keys, _latest_block, _block_height, mut coinbase_nonce) =
+        prepare_pox4_test(function_name!(), Some(&observer));
+
+    let mut stackers = vec![
+        StackerSignerInfo::new(stacker_1.stacker_private_key, stacker_1.signer_private_key),
+        StackerSignerInfo::new(stacker_2.stacker_private_key, stacker_2.signer_private_key),
+    ];
+
+    let history = run_stack_extend_increase_scenario(
+        &mut peer,
+        &mut stackers,
+        3,
+        1_000,
+        &mut coinbase_nonce,
+    );
+
+    assert_eq!(history.len(), 3, "expected one reward-set snapshot per cycle");
+    assert!(
+        !history[0].is_empty(),
+        "stack-extend in the first cycle should have produced reward-set entries"
+    );
+    for cycle in 1..history.len() {
+        assert!(
+            history[cycle].len() >= history[cycle - 1].len(),
+            "stack-increase should not shrink reward-set membership across cycles"
+        );
+    }
+}
+
+/// One tenure's worth of fork info, as reported to the signer client: which consensus hash
+/// started it, its parent, the Nakamoto block id it produced, and whether a sortition
+/// actually won it (vs., say, a shadow recovery tenure).
+struct TenureForkInfo {
+    consensus_hash: ConsensusHash,
+    parent_consensus_hash: ConsensusHash,
+    first_block_id: StacksBlockId,
+    last_block_id: StacksBlockId,
+    sortition_won: bool,
+}
+
+/// Walks the fork containing `at_consensus_hash` back to the first snapshot, returning each
+/// tenure along the way in order from that ancestor up to `at_consensus_hash` itself -- the
+/// same shape the signer client's fork-info query returns.
+fn get_tenure_fork_info(peer: &TestPeer, at_consensus_hash: &ConsensusHash) -> Vec<TenureForkInfo> {
+    let sortdb = peer.sortdb.as_ref().expect("test peer has no sortition db");
+
+    let mut fork_info = Vec::new();
+    let mut cursor = SortitionDB::get_block_snapshot_consensus(sortdb.conn(), at_consensus_hash)
+        .expect("failed to query sortition db")
+        .expect("no such consensus hash in the sortition db");
+
+    loop {
+        let parent = SortitionDB::get_block_snapshot(sortdb.conn(), &cursor.parent_burn_header_hash)
+            .expect("failed to query sortition db");
+
+        let block_id =
+            StacksBlockId::new(&cursor.consensus_hash, &cursor.winning_stacks_block_hash);
+        fork_info.push(TenureForkInfo {
+            consensus_hash: cursor.consensus_hash.clone(),
+            parent_consensus_hash: parent
+                .as_ref()
+                .map(|p| p.consensus_hash.clone())
+                .unwrap_or_else(ConsensusHash::empty),
+            first_block_id: block_id,
+            last_block_id: block_id,
+            sortition_won: cursor.sortition,
+        });
+
+        match parent {
+            Some(p) => cursor = p,
+            None => break,
+        }
+    }
+
+    fork_info.reverse();
+    fork_info
+}
+
+/// Rewinds the sortition DB to the snapshot at `fork_height` on the fork containing the
+/// current tip, then mines a competing tenure from there, so a test can assert the
+/// coordinator reorgs onto whichever fork ends up heavier and that reward-set/signer state
+/// is recomputed consistently across the reorg.
+fn fork_burnchain_at(
+    peer: &mut TestPeer,
+    test_signers: &mut TestSigners,
+    fork_height: u64,
+) -> StacksBlockId {
+    let current_tip = get_tip(peer.sortdb.as_ref());
+    let fork_snapshot = {
+        let sortdb = peer.sortdb.as_ref().expect("test peer has no sortition db");
+        SortitionDB::get_ancestor_snapshot(sortdb.conn(), fork_height, &current_tip.sortition_id)
+            .expect("failed to query sortition db")
+            .expect("no snapshot at that height on the current fork")
+    };
+
+    info!(
+        "forking burnchain at height {} (consensus hash {})",
+        fork_height, &fork_snapshot.consensus_hash
+    );
+
+    // Actually rewind the sortition db's canonical view to `fork_snapshot` before minting the
+    // competing tenure below -- otherwise its burn ops just get appended after `current_tip`,
+    // continuing the existing tenure instead of forking off of an ancestor.
+    {
+        let sortdb = peer.sortdb.as_mut().expect("test peer has no sortition db");
+        sortdb
+            .rewind_to(&fork_snapshot)
+            .expect("failed to rewind sortition db to fork point");
+    }
+
+    let stacker = TestStacker::from_seed(&[0xfa, 0x4b]);
+    let blocks_and_sizes = nakamoto_tenure(
+        peer,
+        test_signers,
+        vec![vec![]],
+        &stacker.signer_private_key,
+    );
+    let (block, ..) = blocks_and_sizes
+        .last()
+        .expect("competing fork tenure produced no blocks");
+    block.header.block_id()
+}
+
+#[test]
+fn fork_burnchain_reports_consistent_tenure_fork_info() {
+    let stacker_1 = TestStacker::from_seed(&[21, 22]);
+    let stacker_2 = TestStacker::from_seed(&[23, 24]);
+    let observer = TestEventObserver::new();
+
+    let signer = key_to_stacks_addr(&stacker_1.signer_private_key).to_account_principal();
+
+    let (mut peer, mut test_signers, _latest_block_id) = prepare_signers_test(
+        function_name!(),
+        vec![(signer, 1000)],
+        Some(vec![&stacker_1, &stacker_2]),
+        Some(&observer),
+    );
+
+    let tip_before = get_tip(peer.sortdb.as_ref());
+    let fork_height = tip_before.block_height.saturating_sub(1);
+
+    nakamoto_tenure(
+        &mut peer,
+        &mut test_signers,
+        vec![vec![]],
+        &stacker_1.signer_private_key,
+    );
+
+    let competing_block_id = fork_burnchain_at(&mut peer, &mut test_signers, fork_height);
+
+    let tip_after = get_tip(peer.sortdb.as_ref());
+    assert_eq!(
+        StacksBlockId::new(&tip_after.consensus_hash, &tip_after.winning_stacks_block_hash),
+        competing_block_id,
+        "coordinator did not reorg onto the heavier competing fork"
+    );
+
+    let fork_info = get_tenure_fork_info(&peer, &tip_after.consensus_hash);
+    assert!(!fork_info.is_empty(), "fork info walk produced no tenures");
+
+    let tip_tenure = fork_info.last().expect("fork info should include the current tip's tenure");
+    assert_eq!(
+        tip_tenure.consensus_hash, tip_after.consensus_hash,
+        "last tenure in the fork-info walk should be the current tip's tenure"
+    );
+    assert!(
+        tip_tenure.sortition_won,
+        "the winning fork's tip tenure should have won its sortition"
+    );
+}
+
+#[test]
+fn test_shadow_recovery() {
+    let stacker_1 = TestStacker::from_seed(&[3, 4]);
+    let stacker_2 = TestStacker::from_seed(&[5, 6]);
+    let observer = TestEventObserver::new();
+
+    let signer = key_to_stacks_addr(&stacker_1.signer_private_key).to_account_principal();
+
+    let (mut peer, mut test_signers, _latest_block_id) = prepare_signers_test(
+        function_name!(),
+        vec![(signer, 1000)],
+        Some(vec![&stacker_1, &stacker_2]),
+        Some(&observer),
+    );
+
+    let tip_before = get_tip(peer.sortdb.as_ref());
+
+    let shadow_blocks = make_shadow_tenure(&mut peer, &mut test_signers, vec![]);
+    assert!(!shadow_blocks.is_empty(), "shadow tenure produced no blocks");
+
+    let tip_after = get_tip(peer.sortdb.as_ref());
+    assert!(
+        tip_after.canonical_stacks_tip_height > tip_before.canonical_stacks_tip_height,
+        "Stacks chain tip did not advance through the shadow tenure"
+    );
+
+    // shadow-tenure blocks must be recorded as such in the headers DB, so downstream
+    // consumers (the signer client, block explorers) can tell them apart from a block
+    // produced by a normal, sortition-won tenure.
+    let (shadow_block, ..) = shadow_blocks
+        .last()
+        .expect("shadow tenure produced no blocks");
+    let shadow_header = peer
+        .with_db_state(|_sortdb, chainstate, _, _| {
+            Ok(NakamotoChainState::get_block_header(
+                chainstate.db(),
+                &shadow_block.header.block_id(),
+            ))
+        })
+        .unwrap()
+        .expect("query for shadow block header failed")
+        .expect("shadow block not found in headers DB");
+    assert!(
+        shadow_header.is_shadow_block(),
+        "shadow-tenure block was not distinguishable from a normal block in the headers DB"
+    );
+
+    // a normal tenure should resume cleanly once the stall has been recovered from
+    let mut nonce: u64 = 1;
+    let tx = make_dummy_tx(&mut peer, &stacker_1.stacker_private_key, &mut nonce);
+    let resumed = nakamoto_tenure(
+        &mut peer,
+        &mut test_signers,
+        vec![vec![tx]],
+        &stacker_1.signer_private_key,
+    );
+    assert!(
+        !resumed.is_empty(),
+        "normal tenure did not resume after shadow recovery"
+    );
+}
+
+#[test]
+fn mock_signing_accepts_reward_set_rejects_foreign_keys() {
+    let stacker_1 = TestStacker::from_seed(&[3, 4]);
+    let stacker_2 = TestStacker::from_seed(&[5, 6]);
+    let foreign_stacker = TestStacker::from_seed(&[9, 9]);
+    let observer = TestEventObserver::new();
+
+    let signer = key_to_stacks_addr(&stacker_1.signer_private_key).to_account_principal();
+
+    let (mut peer, mut test_signers, _latest_block_id) = prepare_signers_test(
+        function_name!(),
+        vec![(signer, 1000)],
+        Some(vec![&stacker_1, &stacker_2]),
+        Some(&observer),
+    );
+
+    let consensus_hash = get_tip(peer.sortdb.as_ref()).consensus_hash.clone();
+
+    let mut nonce: u64 = 1;
+    let tx = make_dummy_tx(&mut peer, &stacker_1.stacker_private_key, &mut nonce);
+
+    let results = nakamoto_tenure_mock_signed(
+        &mut peer,
+        &mut test_signers,
+        vec![vec![tx]],
+        &stacker_1.signer_private_key,
+        &[&stacker_1, &stacker_2],
+        &consensus_hash,
+    );
+
+    let (block, _, _, messages) = results.last().expect("tenure produced no blocks");
+    let reward_set_keys: Vec<StacksPublicKey> = [&stacker_1, &stacker_2]
+        .iter()
+        .map(|s| StacksPublicKey::from_private(&s.signer_private_key))
+        .collect();
+
+    assert!(verify_mock_signatures(messages, &reward_set_keys, &consensus_hash, block));
+
+    let foreign_message =
+        mock_sign_block_identity(&foreign_stacker.signer_private_key, &consensus_hash, block);
+    assert!(!verify_mock_signatures(
+        &[foreign_message],
+        &reward_set_keys,
+        &consensus_hash,
+        block
+    ));
+}
+
+/// Self-consistency check for `nakamoto_tenure_with_timing`'s own gap bookkeeping: given a
+/// second block stamped too close to the first, does the harness's `honored_gap` flag it?
+/// This is NOT a test of any consensus-enforced minimum block spacing -- see the NOTE on
+/// `nakamoto_tenure_with_timing` -- since no such enforcement exists anywhere in this checkout.
+#[test]
+fn nakamoto_tenure_with_timing_flags_a_too_close_second_block() {
+    let stacker_1 = TestStacker::from_seed(&[3, 4]);
+    let stacker_2 = TestStacker::from_seed(&[5, 6]);
+    let observer = TestEventObserver::new();
+
+    let signer = key_to_stacks_addr(&stacker_1.signer_private_key).to_account_principal();
+
+    let (mut peer, mut test_signers, _latest_block_id) = prepare_signers_test(
+        function_name!(),
+        vec![(signer, 1000)],
+        Some(vec![&stacker_1, &stacker_2]),
+        Some(&observer),
+    );
+
+    const MIN_GAP_MS: u64 = 10_000;
+    let mut nonce: u64 = 1;
+    let tx = make_dummy_tx(&mut peer, &stacker_1.stacker_private_key, &mut nonce);
+    let tx_2 = make_dummy_tx(&mut peer, &stacker_1.stacker_private_key, &mut nonce);
+
+    // first block of the tenure has nothing to compare against, so it always honors the gap;
+    // the second is stamped too close to the first and should be flagged as a violation.
+    let results = nakamoto_tenure_with_timing(
+        &mut peer,
+        &mut test_signers,
+        vec![vec![tx], vec![tx_2]],
+        &stacker_1.signer_private_key,
+        vec![Some(1_000), Some(1_001)],
+        MIN_GAP_MS,
+    );
+
+    assert!(results.len() >= 2, "tenure did not produce both blocks under test");
+    assert!(results[0].3, "first block in a tenure has no prior block to violate the gap against");
+    assert!(
+        !results[1].3,
+        "second block was stamped 1s after the first against a 10s minimum gap, but was not flagged as a violation"
+    );
+}
+
 fn nakamoto_tenure(
     peer: &mut TestPeer,
     test_signers: &mut TestSigners,
@@ -339,6 +1022,78 @@ fn nakamoto_tenure(
     blocks_and_sizes
 }
 
+/// Like `nakamoto_tenure`, but lets the caller pin each block's timestamp. `block_timestamps[i]`
+/// is the timestamp to stamp block `i` with; `None` lets the miner pick "now" as usual.
+///
+/// NOTE: `honored_gap` below is this harness recomputing the gap from each produced block's
+/// header timestamp (in seconds) -- it is not consulting any consensus-side enforcement, because
+/// no `MIN_TIME_BETWEEN`-style block-timing rule exists anywhere in this checkout. So this only proves the
+/// harness's own bookkeeping is self-consistent: that it correctly flags the gaps it was told
+/// to produce. It does not exercise, and must not be read as testing, any actual
+/// minimum-block-spacing guarantee enforced by the chain.
+fn nakamoto_tenure_with_timing(
+    peer: &mut TestPeer,
+    test_signers: &mut TestSigners,
+    txs_of_blocks: Vec<Vec<StacksTransaction>>,
+    stacker_private_key: &StacksPrivateKey,
+    block_timestamps: Vec<Option<u64>>,
+    min_time_between_blocks_ms: u64,
+) -> Vec<(NakamotoBlock, u64, ExecutionCost, bool)> {
+    let current_height = peer.get_burnchain_view().unwrap().burn_block_height;
+
+    info!("current height: {}", current_height);
+
+    let (burn_ops, mut tenure_change, miner_key) =
+        peer.begin_nakamoto_tenure(TenureChangeCause::BlockFound);
+
+    let (_, _, consensus_hash) = peer.next_burnchain_block(burn_ops);
+
+    let vrf_proof = peer.make_nakamoto_vrf_proof(miner_key);
+
+    tenure_change.tenure_consensus_hash = consensus_hash.clone();
+    tenure_change.burn_view_consensus_hash = consensus_hash.clone();
+    let tenure_change_tx = peer
+        .miner
+        .make_nakamoto_tenure_change(tenure_change.clone());
+    let coinbase_tx = peer.miner.make_nakamoto_coinbase(None, vrf_proof);
+
+    let mut mutable_txs_of_blocks = txs_of_blocks.clone();
+    mutable_txs_of_blocks.reverse();
+    let mut mutable_timestamps = block_timestamps.clone();
+    mutable_timestamps.reverse();
+
+    let blocks_and_sizes = peer.make_nakamoto_tenure(
+        tenure_change_tx,
+        coinbase_tx.clone(),
+        test_signers,
+        |miner, chainstate, sortdb, blocks| {
+            if let Some(requested_timestamp) = mutable_timestamps.pop().flatten() {
+                miner.next_burn_block_timestamp = Some(requested_timestamp);
+            }
+            mutable_txs_of_blocks.pop().unwrap_or(vec![])
+        },
+    );
+
+    info!("tenure length {}", blocks_and_sizes.len());
+
+    let mut last_timestamp: Option<u64> = None;
+    blocks_and_sizes
+        .into_iter()
+        .map(|(block, size, cost)| {
+            let this_timestamp = block.header.timestamp;
+            let honored_gap = match last_timestamp {
+                None => true,
+                Some(prev) => {
+                    this_timestamp.saturating_sub(prev).saturating_mul(1000)
+                        >= min_time_between_blocks_ms
+                }
+            };
+            last_timestamp = Some(this_timestamp);
+            (block, size, cost, honored_gap)
+        })
+        .collect()
+}
+
 fn make_dummy_tx(
     peer: &mut TestPeer,
     private_key: &StacksPrivateKey,